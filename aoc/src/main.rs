@@ -0,0 +1,255 @@
+use std::path::PathBuf;
+use utils::bench::{self, Benchmark};
+use utils::cli::Options;
+use utils::output::{self, Format};
+use utils::verify::{self, AnswerFile};
+use utils::{Answers, Error, Solution, Timings};
+
+type Solver = fn(&str) -> (Answers, Timings);
+
+const DAYS: &[(u8, Solver)] = &[
+    (day01::Day01::DAY, utils::solve_timed::<day01::Day01>),
+    (day02::Day02::DAY, utils::solve_timed::<day02::Day02>),
+    (day03::Day03::DAY, utils::solve_timed::<day03::Day03>),
+    (day04::Day04::DAY, utils::solve_timed::<day04::Day04>),
+    (day05::Day05::DAY, utils::solve_timed::<day05::Day05>),
+    (day06::Day06::DAY, utils::solve_timed::<day06::Day06>),
+    (day07::Day07::DAY, utils::solve_timed::<day07::Day07>),
+    (day08::Day08::DAY, utils::solve_timed::<day08::Day08>),
+    (day09::Day09::DAY, utils::solve_timed::<day09::Day09>),
+    (day10::Day10::DAY, utils::solve_timed::<day10::Day10>),
+    (day11::Day11::DAY, utils::solve_timed::<day11::Day11>),
+    (day12::Day12::DAY, utils::solve_timed::<day12::Day12>),
+    (day13::Day13::DAY, utils::solve_timed::<day13::Day13>),
+    (day14::Day14::DAY, utils::solve_timed::<day14::Day14>),
+    (day15::Day15::DAY, utils::solve_timed::<day15::Day15>),
+    (day16::Day16::DAY, utils::solve_timed::<day16::Day16>),
+    (day17::Day17::DAY, utils::solve_timed::<day17::Day17>),
+    (day18::Day18::DAY, utils::solve_timed::<day18::Day18>),
+    (day19::Day19::DAY, utils::solve_timed::<day19::Day19>),
+    (day20::Day20::DAY, utils::solve_timed::<day20::Day20>),
+    (day21::Day21::DAY, utils::solve_timed::<day21::Day21>),
+    (day22::Day22::DAY, utils::solve_timed::<day22::Day22>),
+    (day24::Day24::DAY, utils::solve_timed::<day24::Day24>),
+    (day25::Day25::DAY, utils::solve_timed::<day25::Day25>),
+];
+
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    }
+}
+
+fn run() -> Result<(), Error> {
+    let options = Options::from_env()?;
+    utils::log::init(options.verbose);
+    let selection = options
+        .positional
+        .first()
+        .map(String::as_str)
+        .unwrap_or("all");
+    let days = match parse_days(selection) {
+        Some(days) => days,
+        None => {
+            eprintln!("Usage: aoc <day | from..to | from..=to | all> [--bench N] [--report PATH] [--verify] [--format text|json] [-v]");
+            std::process::exit(2);
+        }
+    };
+
+    let mut results: Vec<(u8, Answers, Timings)> = vec![];
+    let mut benchmarks: Vec<Benchmark> = vec![];
+    let mut failed = false;
+    for day in days {
+        let solver = match DAYS.iter().find(|(d, _)| *d == day) {
+            Some((_, solver)) => solver,
+            None if selection.parse::<u8>().is_ok() => {
+                eprintln!("No solution for day {}", day);
+                std::process::exit(1);
+            }
+            None => continue,
+        };
+        if options.verify {
+            match verify_day(day, solver) {
+                Ok(passed) => failed |= !passed,
+                Err(err) => {
+                    eprintln!("Day {}: {}", day, err);
+                    failed = true;
+                }
+            }
+            continue;
+        }
+        let input = match utils::read_full_file(input_path(day).to_str().unwrap()) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("Day {}: {}", day, err);
+                failed = true;
+                continue;
+            }
+        };
+        match options.bench {
+            Some(runs) => benchmarks.push(bench::bench(day, solver, &input, runs)),
+            None => {
+                let (answers, timings) = solver(&input);
+                results.push((day, answers, timings));
+            }
+        }
+    }
+
+    if options.bench.is_some() {
+        print!("{}", bench::render(&benchmarks));
+        bench::write_report(
+            options.report.as_deref().unwrap_or("bench.json"),
+            &benchmarks,
+        )?;
+    } else if !options.verify {
+        match options.format {
+            Format::Text => print!("{}", render_table(&results)),
+            Format::Json => print!("{}", output::to_json(&results)),
+        }
+    }
+    if failed {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+fn day_dir(day: u8) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day{:02}", day))
+}
+
+fn input_path(day: u8) -> PathBuf {
+    day_dir(day).join("input.txt")
+}
+
+// Checks every input recorded in the day's answers.toml, e.g. [example] against example.txt
+fn verify_day(day: u8, solver: &Solver) -> Result<bool, Error> {
+    let expected = AnswerFile::load(&day_dir(day).join("answers.toml"))?;
+    let mut passed = true;
+    for (section, expected) in &expected.sections {
+        let path = day_dir(day).join(format!("{}.txt", section));
+        let input = utils::read_full_file(path.to_str().unwrap())?;
+        let answers = solver(&input).0;
+        let outcomes = verify::check(expected, &answers);
+        passed &= verify::report(&format!("Day {} {}", day, section), &answers, &outcomes);
+    }
+    Ok(passed)
+}
+
+fn parse_days(selection: &str) -> Option<Vec<u8>> {
+    if selection == "all" {
+        return Some(DAYS.iter().map(|(day, _)| *day).collect());
+    }
+    let days: Vec<u8> = if let Some((from, to)) = selection.split_once("..=") {
+        (from.parse().ok()?..=to.parse().ok()?).collect()
+    } else if let Some((from, to)) = selection.split_once("..") {
+        (from.parse().ok()?..to.parse().ok()?).collect()
+    } else {
+        vec![selection.parse().ok()?]
+    };
+    if days.is_empty() || days.iter().any(|day| !(1..=25).contains(day)) {
+        return None;
+    }
+    Some(days)
+}
+
+fn render_table(results: &[(u8, Answers, Timings)]) -> String {
+    let header = [
+        String::from("Day"),
+        String::from("Part one"),
+        String::from("Part two"),
+    ];
+    let rows: Vec<[String; 3]> = results
+        .iter()
+        .map(|(day, answers, _)| {
+            [
+                day.to_string(),
+                answers.part_one.clone(),
+                answers.part_two.clone(),
+            ]
+        })
+        .collect();
+
+    let mut widths = [0usize; 3];
+    for row in std::iter::once(&header).chain(&rows) {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            let longest = cell.lines().map(|l| l.chars().count()).max().unwrap_or(0);
+            *width = (*width).max(longest);
+        }
+    }
+
+    let mut table = String::new();
+    push_row(&mut table, &header, &widths);
+    let separator: Vec<String> = widths.iter().map(|w| "-".repeat(*w)).collect();
+    table.push_str(&separator.join("-+-"));
+    table.push('\n');
+    for row in &rows {
+        push_row(&mut table, row, &widths);
+    }
+    table
+}
+
+// Multi-line answers (such as day 13's rendered letters) span several table lines
+fn push_row(table: &mut String, row: &[String; 3], widths: &[usize; 3]) {
+    let cell_lines: Vec<Vec<&str>> = row.iter().map(|cell| cell.lines().collect()).collect();
+    let height = cell_lines.iter().map(|l| l.len()).max().unwrap_or(0).max(1);
+    for i in 0..height {
+        let line: Vec<String> = cell_lines
+            .iter()
+            .zip(widths)
+            .map(|(lines, width)| format!("{:<width$}", lines.get(i).unwrap_or(&""), width = width))
+            .collect();
+        table.push_str(line.join(" | ").trim_end());
+        table.push('\n');
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn parse_single_day() {
+        assert_eq!(parse_days("15"), Some(vec![15]));
+    }
+
+    #[test]
+    fn parse_day_ranges() {
+        assert_eq!(parse_days("1..4"), Some(vec![1, 2, 3]));
+        assert_eq!(parse_days("23..=25"), Some(vec![23, 24, 25]));
+        assert_eq!(parse_days("0..=3"), None);
+        assert_eq!(parse_days("26"), None);
+    }
+
+    #[test]
+    fn parse_all_skips_missing_days() {
+        let days = parse_days("all").unwrap();
+        assert_eq!(days.len(), 24);
+        assert!(!days.contains(&23));
+    }
+
+    #[test]
+    fn render_multiline_answers() {
+        let results = vec![(
+            13,
+            Answers {
+                part_one: String::from("17"),
+                part_two: String::from("# #\n###"),
+            },
+            Timings {
+                parse: Duration::ZERO,
+                part_one: Duration::ZERO,
+                part_two: Duration::ZERO,
+            },
+        )];
+        assert_eq!(
+            render_table(&results),
+            "Day | Part one | Part two\n\
+             ----+----------+---------\n\
+             13  | 17       | # #\n    |          | ###\n"
+        );
+    }
+}
@@ -0,0 +1,291 @@
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use utils::parse::{self, Parser};
+use utils::{Error, Solution};
+
+// each line is sear floor depth
+//
+// count number of times depth increases
+
+pub fn count_increases(depths: &[u32]) -> usize {
+    depth_changes(depths.iter().copied(), 1).increases
+}
+
+pub fn get_window_totals(depths: &[u32], window: usize) -> Vec<u64> {
+    depths
+        .windows(window)
+        .map(|w| w.iter().map(|&depth| u64::from(depth)).sum())
+        .collect()
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DepthChanges {
+    pub increases: usize,
+    pub decreases: usize,
+    pub unchanged: usize,
+}
+
+// Compares the sum of each window of depths with the window before it. Only
+// the current window is held, so depths can be streamed from any source.
+pub fn depth_changes<I: IntoIterator<Item = u32>>(depths: I, window: usize) -> DepthChanges {
+    assert!(window > 0, "window must hold at least one depth");
+    let mut changes = DepthChanges::default();
+    let mut current: VecDeque<u32> = VecDeque::with_capacity(window + 1);
+    let mut sum: u64 = 0;
+    let mut previous: Option<u64> = None;
+    for depth in depths {
+        current.push_back(depth);
+        sum += u64::from(depth);
+        if current.len() > window {
+            sum -= u64::from(current.pop_front().unwrap());
+        }
+        if current.len() < window {
+            continue;
+        }
+        if let Some(previous) = previous {
+            match sum.cmp(&previous) {
+                Ordering::Greater => changes.increases += 1,
+                Ordering::Less => changes.decreases += 1,
+                Ordering::Equal => changes.unchanged += 1,
+            }
+        }
+        previous = Some(sum);
+    }
+    changes
+}
+
+// depth_changes over a file or stdin, one depth per line
+pub fn stream_depth_changes(path: &str, window: usize) -> Result<DepthChanges, Error> {
+    let mut error = None;
+    let depths = utils::stream_lines(path)?
+        .enumerate()
+        .map_while(|(i, line)| {
+            let depth = line.and_then(|line| {
+                line.trim().parse().map_err(|_| Error::InvalidInput {
+                    column: None,
+                    path: utils::input_name(path).to_string(),
+                    line: i + 1,
+                    message: format!("invalid depth `{}`", line),
+                })
+            });
+            depth.map_err(|err| error = Some(err)).ok()
+        });
+    let changes = depth_changes(depths, window);
+    match error {
+        Some(err) => Err(err),
+        None => Ok(changes),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WindowStats {
+    pub start: usize,
+    pub mean: f64,
+    pub std_dev: f64,
+}
+
+// A reading far from the mean of the window just before it. A window whose
+// readings are all equal has no spread to measure against, so any different
+// reading after it is an anomaly without a sigma score.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Anomaly {
+    pub index: usize,
+    pub depth: u32,
+    pub sigmas: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SonarReport {
+    pub readings: usize,
+    pub increases: usize,
+    // (start index, length) of the first longest strictly increasing run
+    pub longest_increasing_run: (usize, usize),
+    // (index, depth) of the first deepest reading
+    pub deepest: Option<(usize, u32)>,
+    pub windows: Vec<WindowStats>,
+    pub anomalies: Vec<Anomaly>,
+}
+
+pub fn sonar_report(depths: &[u32], window: usize, sigma: f64) -> SonarReport {
+    assert!(window > 0, "window must hold at least one depth");
+    let mut longest_increasing_run = (0, depths.len().min(1));
+    let mut run_start = 0;
+    for i in 1..depths.len() {
+        if depths[i] <= depths[i - 1] {
+            run_start = i;
+        }
+        if i + 1 - run_start > longest_increasing_run.1 {
+            longest_increasing_run = (run_start, i + 1 - run_start);
+        }
+    }
+
+    let deepest = depths
+        .iter()
+        .copied()
+        .enumerate()
+        .rev()
+        .max_by_key(|&(_, depth)| depth);
+
+    let windows: Vec<WindowStats> = get_window_totals(depths, window)
+        .into_iter()
+        .enumerate()
+        .map(|(start, total)| {
+            let mean = total as f64 / window as f64;
+            let variance = depths[start..start + window]
+                .iter()
+                .map(|&depth| (f64::from(depth) - mean).powi(2))
+                .sum::<f64>()
+                / window as f64;
+            WindowStats {
+                start,
+                mean,
+                std_dev: variance.sqrt(),
+            }
+        })
+        .collect();
+
+    let anomalies = windows
+        .iter()
+        .zip(depths.iter().enumerate().skip(window))
+        .filter_map(|(stats, (index, &depth))| {
+            let distance = (f64::from(depth) - stats.mean).abs();
+            let sigmas = if stats.std_dev == 0.0 {
+                None
+            } else {
+                Some(distance / stats.std_dev)
+            };
+            let anomalous = match sigmas {
+                Some(sigmas) => sigmas > sigma,
+                None => distance > 0.0,
+            };
+            anomalous.then_some(Anomaly {
+                index,
+                depth,
+                sigmas,
+            })
+        })
+        .collect();
+
+    SonarReport {
+        readings: depths.len(),
+        increases: count_increases(depths),
+        longest_increasing_run,
+        deepest,
+        windows,
+        anomalies,
+    }
+}
+
+// One depth per line
+pub fn parse_depths(input: &str) -> parse::Result<Vec<u32>> {
+    Parser::new(input).each_line(|p| p.unsigned())
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<u32>;
+
+    fn parse(input: &str) -> Self::Input {
+        Self::try_parse(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> parse::Result<Self::Input> {
+        parse_depths(input)
+    }
+
+    fn part_one(depths: &Self::Input) -> String {
+        count_increases(depths).to_string()
+    }
+
+    fn part_two(depths: &Self::Input) -> String {
+        depth_changes(depths.iter().copied(), 3)
+            .increases
+            .to_string()
+    }
+}
+
+#[test]
+fn count_increases_test() {
+    assert_eq!(
+        count_increases(&[199, 200, 208, 210, 200, 207, 240, 269, 260, 263]),
+        7
+    );
+}
+
+#[test]
+fn get_window_totals_test() {
+    assert_eq!(
+        get_window_totals(&[199, 200, 208, 210, 200, 207, 240, 269, 260, 263], 3),
+        vec![607, 618, 618, 617, 647, 716, 769, 792]
+    );
+}
+
+#[test]
+fn depth_changes_test() {
+    let depths = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+    assert_eq!(
+        depth_changes(depths, 1),
+        DepthChanges {
+            increases: 7,
+            decreases: 2,
+            unchanged: 0
+        }
+    );
+    assert_eq!(
+        depth_changes(depths, 3),
+        DepthChanges {
+            increases: 5,
+            decreases: 1,
+            unchanged: 1
+        }
+    );
+    assert_eq!(depth_changes(depths, 10), DepthChanges::default());
+}
+
+#[test]
+fn parse_depths_test() {
+    assert_eq!(parse_depths("199\n200\n"), Ok(vec![199, 200]));
+    let err = parse_depths("1\n2\nx\n").unwrap_err();
+    assert_eq!((err.line, err.column), (3, 1));
+}
+
+#[test]
+fn sonar_report_test() {
+    let depths = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+    let report = sonar_report(&depths, 3, 2.0);
+    assert_eq!(report.readings, 10);
+    assert_eq!(report.increases, 7);
+    assert_eq!(report.longest_increasing_run, (0, 4));
+    assert_eq!(report.deepest, Some((7, 269)));
+    assert_eq!(report.windows.len(), 8);
+    assert!((report.windows[0].mean - 607.0 / 3.0).abs() < 1e-9);
+    assert!((report.windows[0].std_dev - (146.0f64 / 9.0).sqrt()).abs() < 1e-9);
+    let anomalies: Vec<usize> = report.anomalies.iter().map(|a| a.index).collect();
+    assert_eq!(anomalies, [6, 7]);
+
+    // Flat windows have no spread, so only readings that differ are flagged
+    let report = sonar_report(&[5, 5, 5, 5, 9], 3, 2.0);
+    assert_eq!(
+        report.anomalies,
+        [Anomaly {
+            index: 4,
+            depth: 9,
+            sigmas: None
+        }]
+    );
+}
+
+#[test]
+fn window_totals_do_not_overflow() {
+    let depths = [u32::MAX; 4];
+    assert_eq!(get_window_totals(&depths, 3), [3 * u64::from(u32::MAX); 2]);
+    assert_eq!(
+        sonar_report(&depths, 3, 2.0).windows[0].mean,
+        f64::from(u32::MAX)
+    );
+}
+
+utils::example_tests!(Day01);
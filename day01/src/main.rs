@@ -1,53 +1,96 @@
-use utils;
+use utils::cli::CommandArgs;
+use utils::Error;
 
-// each line is sear floor depth
-//
-// count number of times depth increases
-
-fn count_increases(depths: &[u32]) -> usize {
-    let mut increases: usize = 0;
-    for i in 1..depths.len() {
-        if depths[i] > depths[i - 1] {
-            increases += 1;
-        }
-    }
-    increases
+fn main() {
+    utils::run_with_subcommands::<day01::Day01>(&[("changes", changes), ("report", report)]);
 }
 
-fn get_window_totals(depths: &[u32]) -> Vec<u32> {
-    let mut totals: Vec<u32> = vec![];
-
-    for i in 2..depths.len() {
-        let total = depths[i - 2] + depths[i - 1] + depths[i];
-        totals.push(total);
-    }
-
-    totals
+struct Args {
+    path: String,
+    window: usize,
+    sigma: Option<f64>,
 }
 
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
-
-    let lines = utils::read_file(&args[1]);
-    let depths: Vec<u32> = lines.iter().map(|l| l.parse().unwrap()).collect();
-    println!("Part 1 Depth increases: {}", count_increases(&depths));
-
-    let windows = get_window_totals(&depths);
-    println!("Part 2 Depth increases: {}", count_increases(&windows));
+// [--window K] [--sigma N] [PATH | -], reading stdin when no path is given
+fn parse_args(args: &[String], window: usize, sigma: Option<f64>) -> Result<Args, Error> {
+    let options: &[&str] = if sigma.is_some() {
+        &["--window", "--sigma"]
+    } else {
+        &["--window"]
+    };
+    let args = CommandArgs::parse(args, &[], options)?;
+    let window = match args.parse_value("--window")? {
+        Some(0) => return Err(Error::InvalidArgument(String::from("--window 0"))),
+        size => size.unwrap_or(window),
+    };
+    let sigma = match args.parse_value::<f64>("--sigma")? {
+        Some(n) if n < 0.0 => return Err(Error::InvalidArgument(format!("--sigma {}", n))),
+        n => n.or(sigma),
+    };
+    Ok(Args {
+        path: args.input_path().to_string(),
+        window,
+        sigma,
+    })
 }
 
-#[test]
-fn count_increases_test() {
-    assert_eq!(
-        count_increases(&vec![199, 200, 208, 210, 200, 207, 240, 269, 260, 263]),
-        7
-    );
+// day01 changes [--window K] [PATH | -]
+fn changes(args: &[String]) -> Result<(), Error> {
+    let args = parse_args(args, 1, None)?;
+    let changes = day01::stream_depth_changes(&args.path, args.window)?;
+    println!("Increases: {}", changes.increases);
+    println!("Decreases: {}", changes.decreases);
+    println!("Unchanged: {}", changes.unchanged);
+    Ok(())
 }
 
-#[test]
-fn get_window_totals_test() {
-    assert_eq!(
-        get_window_totals(&vec![199, 200, 208, 210, 200, 207, 240, 269, 260, 263]),
-        vec![607, 618, 618, 617, 647, 716, 769, 792]
+// day01 report [--window K] [--sigma N] [PATH | -]
+fn report(args: &[String]) -> Result<(), Error> {
+    let args = parse_args(args, 3, Some(3.0))?;
+    let sigma = args.sigma.unwrap();
+    let input = utils::read_full_file(&args.path)?;
+    let depths =
+        day01::parse_depths(&input).map_err(|err| Error::invalid_input(&args.path, err))?;
+    let report = day01::sonar_report(&depths, args.window, sigma);
+
+    println!("Readings: {}", report.readings);
+    println!("Increases: {}", report.increases);
+    let (start, len) = report.longest_increasing_run;
+    println!(
+        "Longest increasing run: {} readings from line {}",
+        len,
+        start + 1
     );
+    if let Some((index, depth)) = report.deepest {
+        println!("Deepest point: {} at line {}", depth, index + 1);
+    }
+    println!(
+        "Anomalies (more than {} sigma from the previous {} readings): {}",
+        sigma,
+        args.window,
+        report.anomalies.len()
+    );
+    for anomaly in &report.anomalies {
+        let score = match anomaly.sigmas {
+            Some(sigmas) => format!("{:.2} sigma", sigmas),
+            None => String::from("after a flat window"),
+        };
+        println!(
+            "  line {}: {} ({})",
+            anomaly.index + 1,
+            anomaly.depth,
+            score
+        );
+    }
+    println!("Windows of {}:", args.window);
+    println!("  {:>6}  {:>10}  {:>10}", "Line", "Mean", "Std dev");
+    for stats in &report.windows {
+        println!(
+            "  {:>6}  {:>10.2}  {:>10.2}",
+            stats.start + 1,
+            stats.mean,
+            stats.std_dev
+        );
+    }
+    Ok(())
 }
@@ -0,0 +1,275 @@
+use std::str::FromStr;
+use utils::parse::{self, ParseError, Parser};
+use utils::Solution;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Forward(i32),
+    Down(i32),
+    Up(i32),
+}
+
+impl Command {
+    fn parse(p: &mut Parser) -> parse::Result<Self> {
+        let direction = p.one_of(&[
+            ("forward", Command::Forward as fn(i32) -> Command),
+            ("down", Command::Down),
+            ("up", Command::Up),
+        ])?;
+        p.expect(" ")?;
+        Ok(direction(p.unsigned()?))
+    }
+}
+
+impl FromStr for Command {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Parser::new(s).complete(Command::parse)
+    }
+}
+
+// Errors carry the line number of the offending command
+pub fn parse_commands(input: &str) -> Result<Vec<Command>, ParseError> {
+    Parser::new(input).each_line(Command::parse)
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Submarine {
+    pub position: i32,
+    pub depth: i32,
+    pub aim: i32,
+}
+
+// One way of interpreting a course. New models only need to say how a single
+// command changes their state; follow drives them through the whole course.
+pub trait SubmarineModel {
+    type State: Copy;
+
+    fn apply(&mut self, command: Command);
+    fn state(&self) -> Self::State;
+}
+
+pub fn follow<M: SubmarineModel>(mut model: M, commands: &[Command]) -> M::State {
+    for &command in commands {
+        model.apply(command);
+    }
+    model.state()
+}
+
+// The state at the surface followed by the state after every command
+pub fn trace<M: SubmarineModel>(mut model: M, commands: &[Command]) -> Vec<M::State> {
+    let mut states = vec![model.state()];
+    for &command in commands {
+        model.apply(command);
+        states.push(model.state());
+    }
+    states
+}
+
+// One row per model and step, step 0 being the starting state
+pub fn trace_csv(traces: &[(&str, Vec<Submarine>)]) -> String {
+    let mut csv = String::from("model,step,position,depth,aim\n");
+    for (model, states) in traces {
+        for (step, state) in states.iter().enumerate() {
+            csv.push_str(&format!(
+                "{},{},{},{},{}\n",
+                model, step, state.position, state.depth, state.aim
+            ));
+        }
+    }
+    csv
+}
+
+const PANEL_WIDTH: f64 = 800.0;
+const PANEL_HEIGHT: f64 = 300.0;
+const MARGIN: f64 = 30.0;
+
+// Depth against horizontal position, one panel per model stacked vertically.
+// Each panel is scaled to its own trace since the models dive very differently.
+pub fn trace_svg(traces: &[(&str, Vec<Submarine>)]) -> String {
+    let height = traces.len() as f64 * (PANEL_HEIGHT + MARGIN) + MARGIN;
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\">\n",
+        PANEL_WIDTH + 2.0 * MARGIN,
+        height
+    );
+    for (i, (model, states)) in traces.iter().enumerate() {
+        let top = MARGIN + i as f64 * (PANEL_HEIGHT + MARGIN);
+        let span = |value: fn(&Submarine) -> i32| {
+            let min = states.iter().map(value).min().unwrap_or(0);
+            let max = states.iter().map(value).max().unwrap_or(0);
+            (min as f64, ((max - min) as f64).max(1.0))
+        };
+        let (min_x, width) = span(|s| s.position);
+        let (min_y, depth) = span(|s| s.depth);
+        let points: Vec<String> = states
+            .iter()
+            .map(|s| {
+                let x = MARGIN + (s.position as f64 - min_x) / width * PANEL_WIDTH;
+                let y = top + (s.depth as f64 - min_y) / depth * PANEL_HEIGHT;
+                format!("{:.1},{:.1}", x, y)
+            })
+            .collect();
+        svg.push_str(&format!(
+            "  <text x=\"{}\" y=\"{}\" font-size=\"14\">{}</text>\n",
+            MARGIN,
+            top - 8.0,
+            model
+        ));
+        svg.push_str(&format!(
+            "  <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"none\" stroke=\"#ccc\"/>\n",
+            MARGIN, top, PANEL_WIDTH, PANEL_HEIGHT
+        ));
+        svg.push_str(&format!(
+            "  <polyline fill=\"none\" stroke=\"steelblue\" points=\"{}\"/>\n",
+            points.join(" ")
+        ));
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+// Traces of both puzzle models, as written by `day02 trace`
+pub fn course_traces(commands: &[Command]) -> Vec<(&'static str, Vec<Submarine>)> {
+    vec![
+        ("simple", trace(Simple::default(), commands)),
+        ("aimed", trace(Aimed::default(), commands)),
+    ]
+}
+
+// Up and down change depth directly
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Simple(Submarine);
+
+impl SubmarineModel for Simple {
+    type State = Submarine;
+
+    fn apply(&mut self, command: Command) {
+        match command {
+            Command::Down(amount) => self.0.depth += amount,
+            Command::Up(amount) => self.0.depth -= amount,
+            Command::Forward(amount) => self.0.position += amount,
+        }
+    }
+
+    fn state(&self) -> Submarine {
+        self.0
+    }
+}
+
+// Up and down change aim, and moving forward dives along it
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Aimed(Submarine);
+
+impl SubmarineModel for Aimed {
+    type State = Submarine;
+
+    fn apply(&mut self, command: Command) {
+        match command {
+            Command::Down(amount) => self.0.aim += amount,
+            Command::Up(amount) => self.0.aim -= amount,
+            Command::Forward(amount) => {
+                self.0.position += amount;
+                self.0.depth += self.0.aim * amount;
+            }
+        }
+    }
+
+    fn state(&self) -> Submarine {
+        self.0
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Command>;
+
+    fn parse(input: &str) -> Self::Input {
+        Self::try_parse(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> parse::Result<Self::Input> {
+        parse_commands(input)
+    }
+
+    fn part_one(commands: &Self::Input) -> String {
+        let end = follow(Simple::default(), commands);
+        (end.position * end.depth).to_string()
+    }
+
+    fn part_two(commands: &Self::Input) -> String {
+        let end = follow(Aimed::default(), commands);
+        (end.position * end.depth).to_string()
+    }
+}
+
+#[test]
+fn parse_command() {
+    assert_eq!("forward 5".parse(), Ok(Command::Forward(5)));
+    assert_eq!("up 3".parse(), Ok(Command::Up(3)));
+}
+
+#[test]
+fn unknown_direction_reports_line() {
+    let err = parse_commands("forward 5\ndown 5\nbackward 8\n").unwrap_err();
+    assert_eq!((err.line, err.column), (3, 1));
+    assert_eq!(err.message, "expected one of `forward`, `down`, `up`");
+    let err = parse_commands("forward 5\ndown five\n").unwrap_err();
+    assert_eq!((err.line, err.column), (2, 6));
+}
+
+#[test]
+fn custom_model() {
+    // Tracks the deepest point reached under the aimed interpretation
+    #[derive(Default)]
+    struct Deepest {
+        aimed: Aimed,
+        deepest: i32,
+    }
+
+    impl SubmarineModel for Deepest {
+        type State = i32;
+
+        fn apply(&mut self, command: Command) {
+            self.aimed.apply(command);
+            self.deepest = self.deepest.max(self.aimed.state().depth);
+        }
+
+        fn state(&self) -> i32 {
+            self.deepest
+        }
+    }
+
+    let commands = parse_commands("down 5\nforward 8\nup 10\nforward 2\n").unwrap();
+    assert_eq!(follow(Deepest::default(), &commands), 40);
+    assert_eq!(
+        follow(Aimed::default(), &commands),
+        Submarine {
+            position: 10,
+            depth: 30,
+            aim: -5
+        }
+    );
+}
+
+#[test]
+fn trace_exports() {
+    let commands = parse_commands("forward 5\ndown 5\nforward 8\n").unwrap();
+    let traces = course_traces(&commands);
+    assert_eq!(traces[1].1.len(), 4);
+    assert_eq!(
+        trace_csv(&traces),
+        "model,step,position,depth,aim\n\
+         simple,0,0,0,0\nsimple,1,5,0,0\nsimple,2,5,5,0\nsimple,3,13,5,0\n\
+         aimed,0,0,0,0\naimed,1,5,0,0\naimed,2,5,0,5\naimed,3,13,40,5\n"
+    );
+    let svg = trace_svg(&traces);
+    assert_eq!(svg.matches("<polyline").count(), 2);
+    assert!(svg.contains("points=\"30.0,360.0 337.7,360.0 337.7,360.0 830.0,660.0\""));
+}
+
+utils::example_tests!(Day02);
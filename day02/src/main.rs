@@ -1,50 +1,28 @@
-fn parse_steps(lines: &[String]) -> i32 {
-    let mut position: i32 = 0;
-    let mut depth: i32 = 0;
-    for line in lines {
-        let mut spl = line.split(' ');
-        let direction = spl.next().unwrap();
-        let amount: i32 = spl.next().unwrap().parse().unwrap();
-        match direction {
-            "down" => depth += amount,
-            "up" => depth -= amount,
-            "forward" => position += amount,
-            _ => (),
-        }
-    }
-    position * depth
-}
+use utils::cli::CommandArgs;
+use utils::Error;
 
-fn parse_steps_2(lines: &[String]) -> i32 {
-    let mut aim: i32 = 0;
-    let mut depth: i32 = 0;
-    let mut position: i32 = 0;
-    for line in lines {
-        let mut spl = line.split(' ');
-        let direction = spl.next().unwrap();
-        let amount: i32 = spl.next().unwrap().parse().unwrap();
-        match direction {
-            "down" => {
-                aim += amount;
-            }
-            "up" => {
-                aim -= amount;
-            }
-            "forward" => {
-                position += amount;
-                depth += aim * amount;
-            }
-            _ => (),
-        }
-    }
-    position * depth
+fn main() {
+    utils::run_with_subcommands::<day02::Day02>(&[("trace", trace)]);
 }
 
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    let lines = utils::read_file(&args[1]);
-    let part_1_result = parse_steps(&lines);
-    println!("Part 1 result: {}", part_1_result);
-    let part_2_result = parse_steps_2(&lines);
-    println!("Part 2 result: {}", part_2_result);
+// day02 trace [--csv PATH] [--svg PATH] [PATH | -]
+// Writes the CSV to stdout when neither output is given
+fn trace(args: &[String]) -> Result<(), Error> {
+    let args = CommandArgs::parse(args, &[], &["--csv", "--svg"])?;
+    let path = args.input_path();
+    let input = utils::read_full_file(path)?;
+    let commands = day02::parse_commands(&input).map_err(|err| Error::invalid_input(path, err))?;
+    let traces = day02::course_traces(&commands);
+
+    let (csv, svg) = (args.value("--csv"), args.value("--svg"));
+    if csv.is_none() && svg.is_none() {
+        print!("{}", day02::trace_csv(&traces));
+    }
+    if let Some(out) = csv {
+        utils::write_file(out, day02::trace_csv(&traces))?;
+    }
+    if let Some(out) = svg {
+        utils::write_file(out, day02::trace_svg(&traces))?;
+    }
+    Ok(())
 }
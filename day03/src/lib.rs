@@ -0,0 +1,265 @@
+use utils::{BigUint, Solution};
+
+// Each row is packed into one word with the first column in the highest bit,
+// so a row's value is the binary number it spells out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub width: usize,
+    pub rows: Vec<u128>,
+}
+
+impl Report {
+    fn bit(&self, column: usize) -> u128 {
+        1 << (self.width - 1 - column)
+    }
+
+    // How many rows have a 1 in each column. Blocks of 64 rows are transposed
+    // so that every column becomes a single word and can be popcounted.
+    pub fn column_counts(&self) -> Vec<usize> {
+        let mut counts = vec![0; self.width];
+        for chunk in self.rows.chunks(64) {
+            for half in [0, 64] {
+                if half >= self.width {
+                    continue;
+                }
+                let mut block = [0u64; 64];
+                for (word, &row) in block.iter_mut().zip(chunk) {
+                    *word = (row >> half) as u64;
+                }
+                transpose(&mut block);
+                // Word k of the transposed block holds bit k of every row
+                for (k, word) in block.iter().enumerate() {
+                    let shift = half + k;
+                    if shift < self.width {
+                        counts[self.width - 1 - shift] += word.count_ones() as usize;
+                    }
+                }
+            }
+        }
+        counts
+    }
+}
+
+// Transposes a 64x64 bit matrix in place by swapping ever smaller blocks
+fn transpose(block: &mut [u64; 64]) {
+    let mut width = 32;
+    let mut mask: u64 = 0x0000_0000_ffff_ffff;
+    while width != 0 {
+        let mut k = 0;
+        while k < 64 {
+            let t = ((block[k] >> width) ^ block[k + width]) & mask;
+            block[k] ^= t << width;
+            block[k + width] ^= t;
+            k = (k + width + 1) & !width;
+        }
+        width >>= 1;
+        mask ^= mask << width;
+    }
+}
+
+// Gamma and epsilon can each be 128 bits wide, so their product can need 256
+fn product(a: u128, b: u128) -> String {
+    (&BigUint::from(a) * &BigUint::from(b)).to_string()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Common {
+    Most,
+    Least,
+}
+
+// What to keep when a column has as many ones as zeros
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tie {
+    Zero,
+    One,
+    KeepBoth,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BitCriteria {
+    pub common: Common,
+    pub tie: Tie,
+}
+
+impl BitCriteria {
+    // Also the rules for gamma and epsilon, so both parts agree on ties
+    pub const OXYGEN: BitCriteria = BitCriteria {
+        common: Common::Most,
+        tie: Tie::One,
+    };
+    pub const CO2_SCRUBBER: BitCriteria = BitCriteria {
+        common: Common::Least,
+        tie: Tie::Zero,
+    };
+
+    // The bit to keep in a column, or None to keep both
+    pub fn select(self, ones: usize, zeros: usize) -> Option<bool> {
+        if ones == zeros {
+            return match self.tie {
+                Tie::Zero => Some(false),
+                Tie::One => Some(true),
+                Tie::KeepBoth => None,
+            };
+        }
+        Some((ones > zeros) == (self.common == Common::Most))
+    }
+}
+
+// The number made of the bit each column's counts select, if every column
+// picks one
+fn pick_bits(report: &Report, criteria: BitCriteria) -> Option<u128> {
+    let line_count = report.rows.len();
+    let mut number = 0;
+    for (column, ones) in report.column_counts().into_iter().enumerate() {
+        if criteria.select(ones, line_count - ones)? {
+            number |= report.bit(column);
+        }
+    }
+    Some(number)
+}
+
+fn part_one(report: &Report) -> String {
+    let gamma_rate = pick_bits(report, BitCriteria::OXYGEN).unwrap();
+    let epsilon_rate = pick_bits(report, BitCriteria::CO2_SCRUBBER).unwrap();
+    product(gamma_rate, epsilon_rate)
+}
+
+// Narrows the candidate rows column by column to those with the bit the
+// criteria select. None if more than one distinct row survives every column.
+pub fn rating(report: &Report, criteria: BitCriteria) -> Option<u128> {
+    let mut candidates: Vec<usize> = (0..report.rows.len()).collect();
+    for column in 0..report.width {
+        if candidates.len() == 1 {
+            break;
+        }
+        let bit = report.bit(column);
+        let ones = candidates
+            .iter()
+            .filter(|&&i| report.rows[i] & bit != 0)
+            .count();
+        // Least common would pick the bit no row has and keep nothing
+        if ones == 0 || ones == candidates.len() {
+            continue;
+        }
+        let wanted = criteria.select(ones, candidates.len() - ones);
+        utils::debug!(
+            "Bit {}: {} of {} rows set, keeping {:?}",
+            column,
+            ones,
+            candidates.len(),
+            wanted
+        );
+        if let Some(wanted) = wanted {
+            candidates.retain(|&i| (report.rows[i] & bit != 0) == wanted);
+        }
+    }
+    let first = report.rows[*candidates.first()?];
+    candidates
+        .iter()
+        .all(|&i| report.rows[i] == first)
+        .then_some(first)
+}
+
+fn part_two(report: &Report) -> String {
+    let oxygen = rating(report, BitCriteria::OXYGEN).unwrap();
+    utils::debug!("Oxygen generator rating: {}", oxygen);
+    let scrubber = rating(report, BitCriteria::CO2_SCRUBBER).unwrap();
+    utils::debug!("CO2 scrubber rating: {}", scrubber);
+    product(oxygen, scrubber)
+}
+
+pub fn parse_report(input: &str) -> Report {
+    let width = input.lines().next().expect("Empty report").len();
+    assert!(
+        (1..=128).contains(&width),
+        "Reports must be 1 to 128 columns wide"
+    );
+    let rows = input
+        .lines()
+        .map(|l| {
+            assert_eq!(l.len(), width, "Rows must all be {} columns wide", width);
+            u128::from_str_radix(l, 2).expect("Invalid binary number")
+        })
+        .collect();
+    Report { width, rows }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Report;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_report(input)
+    }
+
+    fn part_one(report: &Self::Input) -> String {
+        part_one(report)
+    }
+
+    fn part_two(report: &Self::Input) -> String {
+        part_two(report)
+    }
+}
+
+#[test]
+fn column_counts_match_naive_count() {
+    let mut state: u128 = 0x2545_f491_4f6c_dd1d;
+    for width in [5, 64, 100, 128] {
+        let rows: Vec<u128> = (0..300)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(0x2360_ed05_1fc6_5da4_4385_df64_9fcc_f645)
+                    .wrapping_add(0x5851_f42d_4c95_7f2d_1405_7b7e_f767_814f);
+                (state ^ (state >> 64)) >> (128 - width)
+            })
+            .collect();
+        let naive: Vec<usize> = (0..width)
+            .map(|column| {
+                rows.iter()
+                    .filter(|&&row| row >> (width - 1 - column) & 1 == 1)
+                    .count()
+            })
+            .collect();
+        let report = Report { width, rows };
+        assert_eq!(report.column_counts(), naive);
+    }
+}
+
+#[test]
+fn wide_products() {
+    assert_eq!(product(0, 12), "0");
+    assert_eq!(product(22, 9), "198");
+    assert_eq!(
+        product(u128::MAX, u128::MAX),
+        "115792089237316195423570985008687907852589419931798687112530834793049593217025"
+    );
+}
+
+#[test]
+fn odd_row_counts() {
+    // One of three rows has the second bit set, so it is not the most common
+    let report = parse_report("11\n10\n00\n");
+    assert_eq!(part_one(&report), "2");
+}
+
+#[test]
+fn tie_breaking() {
+    let report = parse_report("00\n10\n");
+    let most = |tie| BitCriteria {
+        common: Common::Most,
+        tie,
+    };
+    assert_eq!(rating(&report, BitCriteria::OXYGEN), Some(0b10));
+    assert_eq!(rating(&report, BitCriteria::CO2_SCRUBBER), Some(0b00));
+    assert_eq!(rating(&report, most(Tie::Zero)), Some(0b00));
+    assert_eq!(rating(&report, most(Tie::KeepBoth)), None);
+    let report = parse_report("01\n01\n");
+    assert_eq!(rating(&report, most(Tie::KeepBoth)), Some(0b01));
+    assert_eq!(rating(&report, BitCriteria::CO2_SCRUBBER), Some(0b01));
+}
+
+utils::example_tests!(Day03);
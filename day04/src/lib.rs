@@ -0,0 +1,479 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use utils::grid::Point;
+use utils::{Grid, Solution};
+
+// Ways a board can win. Any enabled rule completing is a win.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WinRules {
+    pub lines: bool,
+    pub diagonals: bool,
+    pub four_corners: bool,
+    pub blackout: bool,
+}
+
+impl WinRules {
+    // Rows and columns only, as in the puzzle
+    pub const STANDARD: WinRules = WinRules {
+        lines: true,
+        diagonals: false,
+        four_corners: false,
+        blackout: false,
+    };
+}
+
+#[derive(Debug, Clone)]
+pub struct Board {
+    numbers: Grid<u32>,
+    marked: Grid<bool>,
+    // Where each number sits, so marking is a lookup rather than a scan
+    index: HashMap<u32, Point>,
+    row_marks: Vec<usize>,
+    column_marks: Vec<usize>,
+    diagonal_marks: [usize; 2],
+    marks: usize,
+}
+
+impl Board {
+    pub fn new(numbers: Grid<u32>) -> Self {
+        let index = numbers.cells().map(|(point, &n)| (n, point)).collect();
+        Board {
+            marked: numbers.map(|_| false),
+            row_marks: vec![0; numbers.height()],
+            column_marks: vec![0; numbers.width()],
+            numbers,
+            index,
+            diagonal_marks: [0; 2],
+            marks: 0,
+        }
+    }
+
+    // Whitespace separated rows, which must all be the same length
+    pub fn parse(lines: &[String]) -> Self {
+        let rows: Vec<Vec<u32>> = lines
+            .iter()
+            .map(|l| l.split_whitespace().map(|n| n.parse().unwrap()).collect())
+            .collect();
+        let width = rows.first().expect("Empty board").len();
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "Board rows must all be the same length"
+        );
+        Board::new(Grid::new(width, rows.len(), rows.concat()))
+    }
+
+    pub fn width(&self) -> usize {
+        self.numbers.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.numbers.height()
+    }
+
+    // Marks the number if it is on the board and not already marked
+    pub fn mark(&mut self, number: u32) -> Option<Point> {
+        let point = *self.index.get(&number)?;
+        if std::mem::replace(&mut self.marked[point], true) {
+            return None;
+        }
+        let (x, y) = point;
+        self.row_marks[y] += 1;
+        self.column_marks[x] += 1;
+        if self.is_square() {
+            if x == y {
+                self.diagonal_marks[0] += 1;
+            }
+            if x + y == self.width() - 1 {
+                self.diagonal_marks[1] += 1;
+            }
+        }
+        self.marks += 1;
+        Some(point)
+    }
+
+    // Diagonals only exist on square boards
+    fn is_square(&self) -> bool {
+        self.width() == self.height()
+    }
+
+    fn corners(&self) -> [Point; 4] {
+        let (right, bottom) = (self.width() - 1, self.height() - 1);
+        [(0, 0), (right, 0), (0, bottom), (right, bottom)]
+    }
+
+    pub fn has_won(&self, rules: WinRules) -> bool {
+        let (width, height) = (self.width(), self.height());
+        (rules.lines && (self.row_marks.contains(&width) || self.column_marks.contains(&height)))
+            || (rules.diagonals && self.is_square() && self.diagonal_marks.contains(&width))
+            || (rules.four_corners && self.corners().iter().all(|&corner| self.marked[corner]))
+            || (rules.blackout && self.marks == width * height)
+    }
+
+    // Every set of cells that wins on its own under the rules
+    fn patterns(&self, rules: WinRules) -> Vec<Vec<Point>> {
+        let (width, height) = (self.width(), self.height());
+        let mut patterns: Vec<Vec<Point>> = vec![];
+        if rules.lines {
+            patterns.extend((0..height).map(|y| (0..width).map(|x| (x, y)).collect()));
+            patterns.extend((0..width).map(|x| (0..height).map(|y| (x, y)).collect()));
+        }
+        if rules.diagonals && self.is_square() {
+            patterns.push((0..width).map(|i| (i, i)).collect());
+            patterns.push((0..width).map(|i| (width - 1 - i, i)).collect());
+        }
+        if rules.four_corners {
+            patterns.push(self.corners().to_vec());
+        }
+        if rules.blackout {
+            patterns.push(self.numbers.points().collect());
+        }
+        patterns
+    }
+
+    // How many draws a fresh board takes to win, without playing them out: a
+    // pattern completes on the latest draw among its cells, and the board wins
+    // with whichever pattern completes first.
+    pub fn win_turn(&self, turns: &HashMap<u32, usize>, rules: WinRules) -> Option<usize> {
+        self.patterns(rules)
+            .iter()
+            .filter_map(|pattern| {
+                pattern.iter().try_fold(0, |latest, &point| {
+                    Some(latest.max(*turns.get(&self.numbers[point])?))
+                })
+            })
+            .min()
+    }
+
+    pub fn unmarked_sum(&self) -> u32 {
+        self.numbers
+            .cells()
+            .filter(|&(point, _)| !self.marked[point])
+            .map(|(_, &n)| n)
+            .sum()
+    }
+}
+
+impl fmt::Display for Board {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // At least two wide like the puzzle, more if any number needs it
+        let width = self
+            .numbers
+            .iter()
+            .map(|n| n.to_string().len())
+            .max()
+            .unwrap_or(0)
+            .max(2);
+        for row in self.numbers.rows() {
+            let cells: Vec<String> = row.iter().map(|n| format!("{:>width$}", n)).collect();
+            writeln!(f, "{}", cells.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Bingo {
+    instructions: Vec<u32>,
+    boards: Vec<Board>,
+}
+
+impl Bingo {
+    pub fn draws(&self) -> &[u32] {
+        &self.instructions
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Bingo;
+
+    fn parse(input: &str) -> Self::Input {
+        let mut lines = utils::lines(input);
+        // first line is instructions
+        let instructions: Vec<u32> = lines
+            .first()
+            .unwrap()
+            .split(',')
+            .map(|num| num.parse().unwrap())
+            .collect::<Vec<u32>>();
+        // second line is blank
+        lines.drain(0..2);
+
+        // Extra blank lines, such as trailing ones, leave empty groups
+        let boards = lines
+            .split(|l| l.is_empty())
+            .filter(|group| !group.is_empty())
+            .map(Board::parse)
+            .collect();
+
+        Bingo {
+            instructions,
+            boards,
+        }
+    }
+
+    fn part_one(bingo: &Self::Input) -> String {
+        let winners = winners(bingo, WinRules::STANDARD);
+        winners.first().expect("No board wins").score.to_string()
+    }
+
+    fn part_two(bingo: &Self::Input) -> String {
+        let winners = winners(bingo, WinRules::STANDARD);
+        winners.last().expect("No board wins").score.to_string()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Winner {
+    pub board: usize,
+    // The draw that completed the board, and how many draws it took
+    pub number: u32,
+    pub calls: usize,
+    pub score: u32,
+}
+
+// Every board that wins, in the order they win. Boards completed by the same
+// draw are listed in board order.
+pub fn winners(bingo: &Bingo, rules: WinRules) -> Vec<Winner> {
+    let mut boards = bingo.boards.clone();
+    let mut won = vec![false; boards.len()];
+    let mut winners = vec![];
+
+    for (call, &number) in bingo.instructions.iter().enumerate() {
+        for (i, board) in boards.iter_mut().enumerate() {
+            if won[i] || board.mark(number).is_none() || !board.has_won(rules) {
+                continue;
+            }
+            won[i] = true;
+            let winner = Winner {
+                board: i,
+                number,
+                calls: call + 1,
+                score: board.unmarked_sum() * number,
+            };
+            utils::debug!("{:?}", winner);
+            winners.push(winner);
+        }
+    }
+    winners
+}
+
+// The turn each number is first drawn on, counting from 1
+pub fn draw_turns(draws: &[u32]) -> HashMap<u32, usize> {
+    let mut turns = HashMap::new();
+    for (i, &number) in draws.iter().enumerate() {
+        turns.entry(number).or_insert(i + 1);
+    }
+    turns
+}
+
+// Boards from fastest to slowest win, then the boards that never win
+pub fn rank_boards(bingo: &Bingo, rules: WinRules) -> Vec<(usize, Option<usize>)> {
+    let turns = draw_turns(&bingo.instructions);
+    let mut ranking: Vec<(usize, Option<usize>)> = bingo
+        .boards
+        .iter()
+        .enumerate()
+        .map(|(i, board)| (i, board.win_turn(&turns, rules)))
+        .collect();
+    ranking.sort_by_key(|&(i, turn)| (turn.is_none(), turn, i));
+    ranking
+}
+
+// The fewest draws before any board wins
+pub fn fewest_draws(bingo: &Bingo, rules: WinRules) -> Option<usize> {
+    rank_boards(bingo, rules).first()?.1
+}
+
+// SplitMix64, enough to lay out fixtures reproducibly
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+// A random board that first wins on draw number `turn`, under any rules that
+// include lines. One row holds that draw and earlier ones; every other cell
+// holds a number not drawn by then, so nothing else completes sooner. None if
+// the draws can't fill such a row.
+pub fn generate_board(
+    seed: u64,
+    width: usize,
+    height: usize,
+    draws: &[u32],
+    turn: usize,
+) -> Option<Board> {
+    let drawn = draws.get(..turn)?;
+    let (&last, earlier) = drawn.split_last()?;
+    if width == 0 || height < 2 || earlier.contains(&last) {
+        return None;
+    }
+    let mut earlier: Vec<u32> = earlier.to_vec();
+    earlier.sort_unstable();
+    earlier.dedup();
+    if earlier.len() < width - 1 {
+        return None;
+    }
+
+    let mut rng = Rng(seed);
+    rng.shuffle(&mut earlier);
+    let mut row = earlier[..width - 1].to_vec();
+    row.push(last);
+    rng.shuffle(&mut row);
+
+    let needed = width * (height - 1);
+    let drawn: HashSet<u32> = drawn.iter().copied().collect();
+    let top = draws.iter().max().map_or(0, |&n| n + 1) + needed as u32;
+    let mut fillers: Vec<u32> = (0..top).filter(|n| !drawn.contains(n)).collect();
+    rng.shuffle(&mut fillers);
+    fillers.truncate(needed);
+
+    let winning_row = rng.below(height);
+    let mut cells = fillers;
+    let at = winning_row * width;
+    cells.splice(at..at, row);
+    Some(Board::new(Grid::new(width, height, cells)))
+}
+
+#[test]
+fn win_rules() {
+    let lines = |s: &str| s.lines().map(String::from).collect::<Vec<_>>();
+    let board = Board::parse(&lines("1 2 3\n4 5 6\n7 8 9"));
+    let after = |numbers: &[u32]| {
+        let mut board = board.clone();
+        for &n in numbers {
+            board.mark(n);
+        }
+        board
+    };
+    let none = WinRules {
+        lines: false,
+        diagonals: false,
+        four_corners: false,
+        blackout: false,
+    };
+    let diagonals = WinRules {
+        diagonals: true,
+        ..none
+    };
+    let four_corners = WinRules {
+        four_corners: true,
+        ..none
+    };
+    let blackout = WinRules {
+        blackout: true,
+        ..none
+    };
+
+    assert!(after(&[2, 5, 8]).has_won(WinRules::STANDARD));
+    assert!(!after(&[1, 5, 9]).has_won(WinRules::STANDARD));
+    assert!(after(&[3, 5, 7]).has_won(diagonals));
+    assert!(after(&[1, 3, 7, 9]).has_won(four_corners));
+    assert!(!after(&[1, 3, 7, 9]).has_won(blackout));
+    assert!(after(&[1, 2, 3, 4, 5, 6, 7, 8, 9]).has_won(blackout));
+    assert_eq!(after(&[1, 2, 3, 4, 5, 6, 7, 8, 9]).unmarked_sum(), 0);
+
+    // Rectangular boards have no diagonals, and marking twice counts once
+    let wide = Board::parse(&lines("1 2 3\n4 5 6"));
+    let mut marked = wide.clone();
+    for n in [1, 5, 5, 42] {
+        marked.mark(n);
+    }
+    assert!(!marked.has_won(diagonals));
+    assert!(!marked.has_won(WinRules::STANDARD));
+    assert_eq!(marked.unmarked_sum(), 2 + 3 + 4 + 6);
+}
+
+#[test]
+fn extra_blank_lines() {
+    let bingo = Day04::parse("7,4\n\n1 2\n3 4\n\n\n5 6\n7 8\n\n");
+    assert_eq!(bingo.boards.len(), 2);
+    assert_eq!(bingo.boards[1].width(), 2);
+}
+
+#[test]
+fn example_winners() {
+    let bingo = Day04::parse(include_str!("../example.txt"));
+    let winners = winners(&bingo, WinRules::STANDARD);
+    assert_eq!(
+        winners.iter().map(|w| w.board).collect::<Vec<_>>(),
+        [2, 0, 1]
+    );
+    assert_eq!(
+        winners[0],
+        Winner {
+            board: 2,
+            number: 24,
+            calls: 12,
+            score: 4512
+        }
+    );
+    assert_eq!(
+        winners[2],
+        Winner {
+            board: 1,
+            number: 13,
+            calls: 15,
+            score: 1924
+        }
+    );
+}
+
+#[test]
+fn ranking_agrees_with_play() {
+    let mut bingo = Day04::parse(include_str!("../example.txt"));
+    let diagonals = WinRules {
+        diagonals: true,
+        ..WinRules::STANDARD
+    };
+    for rules in [WinRules::STANDARD, diagonals] {
+        let played: Vec<(usize, Option<usize>)> = winners(&bingo, rules)
+            .iter()
+            .map(|w| (w.board, Some(w.calls)))
+            .collect();
+        assert_eq!(rank_boards(&bingo, rules), played);
+    }
+    assert_eq!(fewest_draws(&bingo, WinRules::STANDARD), Some(12));
+    assert_eq!(fewest_draws(&bingo, diagonals), Some(8));
+
+    // Boards that never win go last
+    bingo.instructions.truncate(12);
+    assert_eq!(rank_boards(&bingo, WinRules::STANDARD)[1], (0, None));
+}
+
+#[test]
+fn generated_boards_win_on_time() {
+    let draws = Day04::parse(include_str!("../example.txt")).instructions;
+    for seed in 0..20 {
+        for turn in [5, 9, 20, 27] {
+            let board = generate_board(seed, 5, 5, &draws, turn).unwrap();
+            let bingo = Bingo {
+                instructions: draws.clone(),
+                boards: vec![board],
+            };
+            assert_eq!(winners(&bingo, WinRules::STANDARD)[0].calls, turn);
+        }
+    }
+    assert!(generate_board(1, 5, 5, &draws, 4).is_none());
+    assert!(generate_board(1, 5, 5, &draws, 28).is_none());
+}
+
+utils::example_tests!(Day04);
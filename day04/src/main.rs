@@ -1,91 +1,57 @@
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    let mut lines = utils::read_file(&args[1]);
-    // first line is instructions
-    let instructions: Vec<u32> = lines
-        .first()
-        .unwrap()
-        .split(',')
-        .map(|num| num.parse().unwrap())
-        .collect::<Vec<u32>>();
-    // second line is blank
-    lines.drain(0..2);
-
-    let mut grids: Vec<Vec<Vec<Option<u32>>>> = lines
-        .split(|l| l.is_empty())
-        .map(|group| parse_grid(group))
-        .collect();
-
-    let mut grids_won: Vec<usize> = vec![];
-
-    for drawn_number in instructions {
-        for i in 0..grids.len() {
-            clear_grid_value(&mut grids[i], drawn_number);
-            if !grids_won.contains(&i) {
-                let has_won = has_grid_won(&grids[i]);
-                if has_won {
-                    println!("Grid {} has won", i);
-                    println!("Grid total {}", sum_board(&grids[i]));
-                    println!("Final score {}", sum_board(&grids[i]) * drawn_number);
-                    grids_won.push(i);
-                }
-            }
-        }
-    }
-}
-
-fn parse_grid(lines: &[String]) -> Vec<Vec<Option<u32>>> {
-    lines
-        .iter()
-        .map(|l| {
-            l.split_whitespace()
-                .map(|n| Some(n.parse().unwrap()))
-                .collect::<Vec<Option<u32>>>()
-        })
-        .collect()
-}
+use day04::WinRules;
+use utils::cli::CommandArgs;
+use utils::{Error, Solution};
 
-fn clear_grid_value(grid: &mut Vec<Vec<Option<u32>>>, value: u32) {
-    for i in 0..grid.len() {
-        for j in 0..grid[i].len() {
-            if grid[i][j] == Some(value) {
-                grid[i][j] = None;
-            }
-        }
-    }
+fn main() {
+    utils::run_with_subcommands::<day04::Day04>(&[("rank", rank), ("generate", generate)]);
 }
 
-fn has_grid_won(grid: &Vec<Vec<Option<u32>>>) -> bool {
-    // check rows
-    for row in 0..grid.len() {
-        if grid[row].iter().all(|x| x.is_none()) {
-            return true;
+// day04 rank [--diagonals] [PATH | -]
+fn rank(args: &[String]) -> Result<(), Error> {
+    let args = CommandArgs::parse(args, &["--diagonals"], &[])?;
+    let bingo = day04::Day04::parse(&utils::read_full_file(args.input_path())?);
+    let rules = WinRules {
+        diagonals: args.switch("--diagonals"),
+        ..WinRules::STANDARD
+    };
+
+    println!("{:>6}  {:>6}", "Board", "Draws");
+    for (board, turn) in day04::rank_boards(&bingo, rules) {
+        match turn {
+            Some(turn) => println!("{:>6}  {:>6}", board, turn),
+            None => println!("{:>6}  {:>6}", board, "never"),
         }
     }
-
-    for column in 0..grid[0].len() {
-        let mut has_some = false;
-        for row in 0..grid.len() {
-            if grid[row][column].is_some() {
-                has_some = true;
-            }
-        }
-        if !has_some {
-            return true;
-        }
+    match day04::fewest_draws(&bingo, rules) {
+        Some(draws) => println!("Fewest draws to a win: {}", draws),
+        None => println!("No board wins"),
     }
-
-    false
+    Ok(())
 }
 
-fn sum_board(grid: &Vec<Vec<Option<u32>>>) -> u32 {
-    let mut acc: u32 = 0;
-    for i in 0..grid.len() {
-        for j in 0..grid[i].len() {
-            if let Some(val) = grid[i][j] {
-                acc += val;
-            }
-        }
-    }
-    acc
+// day04 generate --turn T [--seed S] [--size WxH] [PATH | -]
+// Prints a board that first wins on draw T of the input's draws
+fn generate(args: &[String]) -> Result<(), Error> {
+    let args = CommandArgs::parse(args, &[], &["--turn", "--seed", "--size"])?;
+    let turn = args
+        .parse_value("--turn")?
+        .ok_or(Error::MissingArgument("--turn"))?;
+    let seed = args.parse_value("--seed")?.unwrap_or(0);
+    let (width, height) = match args.value("--size") {
+        None => (5, 5),
+        Some(size) => size
+            .split_once('x')
+            .and_then(|(w, h)| Some((w.parse().ok()?, h.parse().ok()?)))
+            .ok_or_else(|| Error::InvalidArgument(format!("--size {}", size)))?,
+    };
+    let bingo = day04::Day04::parse(&utils::read_full_file(args.input_path())?);
+    let board =
+        day04::generate_board(seed, width, height, bingo.draws(), turn).ok_or_else(|| {
+            Error::InvalidArgument(format!(
+                "no {}x{} board can win on draw {}",
+                width, height, turn
+            ))
+        })?;
+    print!("{}", board);
+    Ok(())
 }
@@ -0,0 +1,601 @@
+use std::collections::{BTreeMap, HashMap};
+use std::num::ParseIntError;
+use std::str::FromStr;
+use utils::{Grid, Solution};
+
+#[derive(Hash, Eq, PartialEq, Debug)]
+pub struct Point(pub i32, pub i32);
+
+impl FromStr for Point {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let values: Vec<i32> = s.split(',').map(|c| c.parse().unwrap()).collect();
+        Ok(Point(values[0], values[1]))
+    }
+}
+
+#[derive(Hash, Eq, PartialEq, Debug)]
+pub struct Line {
+    start: Point,
+    end: Point,
+}
+
+impl FromStr for Line {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let sides: Vec<&str> = s.split(" -> ").collect();
+        Ok(Line {
+            start: sides[0].parse().unwrap(),
+            end: sides[1].parse().unwrap(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LineKind {
+    Point,
+    Horizontal,
+    Vertical,
+    Diagonal,
+    Sloped,
+}
+
+// Which kinds of line get counted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Straight,
+    Diagonal,
+    AnySlope,
+}
+
+impl LineKind {
+    pub const ALL: [LineKind; 5] = [
+        LineKind::Point,
+        LineKind::Horizontal,
+        LineKind::Vertical,
+        LineKind::Diagonal,
+        LineKind::Sloped,
+    ];
+
+    pub fn included(self, mode: Mode) -> bool {
+        match self {
+            LineKind::Point | LineKind::Horizontal | LineKind::Vertical => true,
+            LineKind::Diagonal => mode != Mode::Straight,
+            LineKind::Sloped => mode == Mode::AnySlope,
+        }
+    }
+}
+
+impl Line {
+    fn is_horizontal(&self) -> bool {
+        self.start.1 == self.end.1
+    }
+    fn is_vertical(&self) -> bool {
+        self.start.0 == self.end.0
+    }
+
+    fn is_diagonal(&self) -> bool {
+        (self.start.0 - self.end.0).abs() == (self.start.1 - self.end.1).abs()
+    }
+
+    pub fn kind(&self) -> LineKind {
+        if self.start == self.end {
+            LineKind::Point
+        } else if self.is_horizontal() {
+            LineKind::Horizontal
+        } else if self.is_vertical() {
+            LineKind::Vertical
+        } else if self.is_diagonal() {
+            LineKind::Diagonal
+        } else {
+            LineKind::Sloped
+        }
+    }
+
+    // Every lattice point the line passes through, from start to end, if the
+    // mode counts this kind of line. Dividing the offset by the gcd of its
+    // parts gives the step between neighbouring lattice points.
+    pub fn all_points(&self, mode: Mode) -> Vec<Point> {
+        if !self.kind().included(mode) {
+            return vec![];
+        }
+        let (dx, dy) = (self.end.0 - self.start.0, self.end.1 - self.start.1);
+        let steps = gcd(dx as i64, dy as i64) as i32;
+        if steps == 0 {
+            return vec![Point(self.start.0, self.start.1)];
+        }
+        let (step_x, step_y) = (dx / steps, dy / steps);
+        (0..=steps)
+            .map(|i| Point(self.start.0 + i * step_x, self.start.1 + i * step_y))
+            .collect()
+    }
+}
+
+// Counts points that appear at least min_cover times. Every point has to be
+// listed, so this is only practical for small fields; see sweep_overlaps
+// otherwise.
+pub fn count_overlaps(points: &[Point], min_cover: u32) -> u32 {
+    let mut points_hash = HashMap::new();
+    for point in points {
+        let entry = points_hash.entry(point).or_insert(0);
+        *entry += 1;
+    }
+
+    let mut overlaps: u32 = 0;
+    for (_key, value) in points_hash {
+        if value >= min_cover {
+            overlaps += 1;
+        }
+    }
+    overlaps
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a.abs()
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+// The smallest step between lattice points along a direction, pointing right
+// (or down when vertical) so that parallel lines share it
+type Direction = (i64, i64);
+
+fn direction(dx: i64, dy: i64) -> Direction {
+    let g = gcd(dx, dy);
+    let (dx, dy) = (dx / g, dy / g);
+    if dx < 0 || (dx == 0 && dy < 0) {
+        (-dx, -dy)
+    } else {
+        (dx, dy)
+    }
+}
+
+// Which of the parallel lines along a direction a point lies on
+fn line_key((dx, dy): Direction, (x, y): (i64, i64)) -> i64 {
+    dy * x - dx * y
+}
+
+// Consecutive lattice points along a line get consecutive positions
+fn position((dx, dy): Direction, (x, y): (i64, i64)) -> i64 {
+    (x * dx + y * dy).div_euclid(dx * dx + dy * dy)
+}
+
+// How many lines cover each stretch of positions along one line. Stretches
+// are disjoint, sorted and only cover positions with at least one line.
+#[derive(Debug, Default)]
+struct Coverage {
+    stretches: Vec<(i64, i64, u32)>,
+}
+
+impl Coverage {
+    fn from_spans(spans: &[(i64, i64)]) -> Self {
+        let mut events: Vec<(i64, i32)> = spans
+            .iter()
+            .flat_map(|&(start, end)| [(start, 1), (end + 1, -1)])
+            .collect();
+        events.sort_unstable();
+        let mut stretches = vec![];
+        let mut count = 0;
+        for pair in events.windows(2) {
+            count += pair[0].1;
+            let (start, end) = (pair[0].0, pair[1].0 - 1);
+            if count > 0 && start <= end {
+                stretches.push((start, end, count as u32));
+            }
+        }
+        Coverage { stretches }
+    }
+
+    fn at(&self, position: i64) -> u32 {
+        let i = self
+            .stretches
+            .partition_point(|&(_, end, _)| end < position);
+        match self.stretches.get(i) {
+            Some(&(start, _, count)) if start <= position => count,
+            _ => 0,
+        }
+    }
+
+    fn points_covered(&self, min_cover: u32) -> u64 {
+        self.stretches
+            .iter()
+            .filter(|&&(_, _, count)| count >= min_cover)
+            .map(|&(start, end, _)| (end - start + 1) as u64)
+            .sum()
+    }
+}
+
+// Counts points covered by at least min_cover of the given lines without
+// visiting every point. Parallel lines on the same line are swept together;
+// lines in different directions only meet at single points, which are
+// corrected for afterwards.
+fn count_covered<'a, I>(lines: I, min_cover: u32) -> u64
+where
+    I: IntoIterator<Item = &'a Line>,
+{
+    let mut spans: HashMap<Direction, BTreeMap<i64, Vec<(i64, i64)>>> = HashMap::new();
+    for line in lines {
+        let start = (line.start.0 as i64, line.start.1 as i64);
+        let end = (line.end.0 as i64, line.end.1 as i64);
+        let dir = match (end.0 - start.0, end.1 - start.1) {
+            (0, 0) => (1, 0),
+            (dx, dy) => direction(dx, dy),
+        };
+        let (a, b) = (position(dir, start), position(dir, end));
+        spans
+            .entry(dir)
+            .or_default()
+            .entry(line_key(dir, start))
+            .or_default()
+            .push((a.min(b), a.max(b)));
+    }
+    let coverage: Vec<(Direction, BTreeMap<i64, Coverage>)> = spans
+        .into_iter()
+        .map(|(dir, lines)| {
+            let lines = lines
+                .into_iter()
+                .map(|(key, spans)| (key, Coverage::from_spans(&spans)))
+                .collect();
+            (dir, lines)
+        })
+        .collect();
+
+    let mut total: u64 = coverage
+        .iter()
+        .flat_map(|(_, lines)| lines.values())
+        .map(|c| c.points_covered(min_cover))
+        .sum();
+
+    // Where lines in different directions cross, the count so far included
+    // the point once for every direction covering it enough on its own
+    let mut crossings: HashMap<(i64, i64), Vec<(Direction, u32)>> = HashMap::new();
+    for (i, (d1, lines1)) in coverage.iter().enumerate() {
+        for (d2, lines2) in &coverage[i + 1..] {
+            let det = d1.0 * d2.1 - d2.0 * d1.1;
+            for (&k1, c1) in lines1 {
+                for (&k2, c2) in lines2 {
+                    let (x, y) = (d1.0 * k2 - d2.0 * k1, d1.1 * k2 - d2.1 * k1);
+                    if x % det != 0 || y % det != 0 {
+                        continue;
+                    }
+                    let point = (x / det, y / det);
+                    let (n1, n2) = (c1.at(position(*d1, point)), c2.at(position(*d2, point)));
+                    if n1 == 0 || n2 == 0 {
+                        continue;
+                    }
+                    let counts = crossings.entry(point).or_default();
+                    for (dir, n) in [(*d1, n1), (*d2, n2)] {
+                        if !counts.iter().any(|&(d, _)| d == dir) {
+                            counts.push((dir, n));
+                        }
+                    }
+                }
+            }
+        }
+    }
+    for counts in crossings.values() {
+        let counted = counts.iter().filter(|&&(_, n)| n >= min_cover).count() as u64;
+        let covered = counts.iter().map(|&(_, n)| n).sum::<u32>() >= min_cover;
+        total = total + covered as u64 - counted;
+    }
+    total
+}
+
+// The same answer as count_overlaps over every line's points, but by sweeping
+// along the lines so that long lines cost no more than short ones
+pub fn sweep_overlaps(lines: &[Line], mode: Mode, min_cover: u32) -> u64 {
+    count_covered(
+        lines.iter().filter(|line| line.kind().included(mode)),
+        min_cover,
+    )
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct OverlapReport {
+    pub overlaps: u64,
+    // Each kind of line with how many were counted and how many ignored
+    pub kinds: Vec<(LineKind, usize, usize)>,
+}
+
+pub fn overlap_report(lines: &[Line], mode: Mode, min_cover: u32) -> OverlapReport {
+    let kinds = LineKind::ALL
+        .iter()
+        .map(|&kind| {
+            let count = lines.iter().filter(|line| line.kind() == kind).count();
+            if kind.included(mode) {
+                (kind, count, 0)
+            } else {
+                (kind, 0, count)
+            }
+        })
+        .collect();
+    OverlapReport {
+        overlaps: sweep_overlaps(lines, mode, min_cover),
+        kinds,
+    }
+}
+
+// How many lines cover each point of the bounding box around the lines
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Field {
+    pub origin: (i32, i32),
+    pub counts: Grid<u32>,
+}
+
+impl Field {
+    pub fn new(lines: &[Line], mode: Mode) -> Self {
+        let points: Vec<Point> = lines.iter().flat_map(|l| l.all_points(mode)).collect();
+        let bounds = |coord: fn(&Point) -> i32| {
+            let min = points.iter().map(coord).min().unwrap_or(0);
+            let max = points.iter().map(coord).max().unwrap_or(-1);
+            (min, (max - min + 1) as usize)
+        };
+        let ((x, width), (y, height)) = (bounds(|p| p.0), bounds(|p| p.1));
+        let mut counts = Grid::new(width, height, vec![0; width * height]);
+        for Point(px, py) in points {
+            counts[((px - x) as usize, (py - y) as usize)] += 1;
+        }
+        Field {
+            origin: (x, y),
+            counts,
+        }
+    }
+
+    pub fn overlaps(&self, min_cover: u32) -> usize {
+        self.counts.iter().filter(|&&n| n >= min_cover).count()
+    }
+
+    // The diagram from the puzzle: . for no lines, otherwise how many, with +
+    // standing in for ten or more
+    pub fn to_ascii(&self) -> String {
+        let mut diagram = String::new();
+        for row in self.counts.rows() {
+            diagram.extend(row.iter().map(|&n| match n {
+                0 => '.',
+                1..=9 => char::from_digit(n, 10).unwrap(),
+                _ => '+',
+            }));
+            diagram.push('\n');
+        }
+        diagram
+    }
+
+    fn image(&self, magic: &str, pixel: impl Fn(u32) -> Vec<u8>) -> Vec<u8> {
+        let header = format!(
+            "{}\n{} {}\n255\n",
+            magic,
+            self.counts.width(),
+            self.counts.height()
+        );
+        let mut image = header.into_bytes();
+        image.extend(self.counts.iter().flat_map(|&n| pixel(n)));
+        image
+    }
+
+    // Binary greyscale, brighter where more lines cross
+    pub fn to_pgm(&self) -> Vec<u8> {
+        let max = self.counts.iter().copied().max().unwrap_or(0).max(1);
+        self.image("P5", |n| vec![(n * 255 / max) as u8])
+    }
+
+    // Binary colour: grey for points covered fewer than min_cover times, then
+    // red through to yellow for the overlaps
+    pub fn to_ppm(&self, min_cover: u32) -> Vec<u8> {
+        let max = self.counts.iter().copied().max().unwrap_or(0);
+        self.image("P6", |n| {
+            if n == 0 {
+                vec![0, 0, 0]
+            } else if n < min_cover {
+                let grey = (64 + 128 * n / min_cover) as u8;
+                vec![grey, grey, grey]
+            } else {
+                let green = 255 * (n - min_cover) / (max - min_cover).max(1);
+                vec![255, green as u8, 0]
+            }
+        })
+    }
+}
+
+pub fn parse_lines(input: &str) -> Vec<Line> {
+    input.lines().map(|l| l.parse().unwrap()).collect()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Vec<Line>;
+
+    fn parse(input: &str) -> Self::Input {
+        let lines = parse_lines(input);
+        let sloped = lines
+            .iter()
+            .filter(|line| line.kind() == LineKind::Sloped)
+            .count();
+        if sloped > 0 {
+            utils::warn!(
+                "{} lines are neither straight nor diagonal, and are ignored by both parts",
+                sloped
+            );
+        }
+        lines
+    }
+
+    fn part_one(lines: &Self::Input) -> String {
+        sweep_overlaps(lines, Mode::Straight, 2).to_string()
+    }
+
+    fn part_two(lines: &Self::Input) -> String {
+        sweep_overlaps(lines, Mode::Diagonal, 2).to_string()
+    }
+}
+
+#[test]
+fn parse_line() {
+    let input = "0,9 -> 5,9";
+    let line: Line = input.parse().unwrap();
+    assert_eq!(
+        line,
+        Line {
+            start: Point(0, 9),
+            end: Point(5, 9)
+        }
+    );
+}
+
+#[test]
+fn is_diagonal_test() {
+    let input = "8,0 -> 0,8";
+    let line: Line = input.parse().unwrap();
+    assert!(line.is_diagonal());
+}
+
+#[test]
+fn get_all_horizontal_points() {
+    let input = "0,9 -> 2,9";
+    let line: Line = input.parse().unwrap();
+    let points = line.all_points(Mode::Straight);
+    assert_eq!(points, vec![Point(0, 9), Point(1, 9), Point(2, 9)]);
+}
+
+#[test]
+fn get_all_diagonal_points() {
+    let input = "3,1 -> 1,3";
+    let line: Line = input.parse().unwrap();
+    let points = line.all_points(Mode::Diagonal);
+    assert_eq!(points, vec![Point(3, 1), Point(2, 2), Point(1, 3)]);
+}
+
+#[test]
+fn get_all_sloped_points() {
+    let line: Line = "6,1 -> 0,5".parse().unwrap();
+    assert_eq!(line.kind(), LineKind::Sloped);
+    assert!(line.all_points(Mode::Diagonal).is_empty());
+    assert_eq!(
+        line.all_points(Mode::AnySlope),
+        vec![Point(6, 1), Point(3, 3), Point(0, 5)]
+    );
+    let line: Line = "2,2 -> 2,2".parse().unwrap();
+    assert_eq!(line.kind(), LineKind::Point);
+    assert_eq!(line.all_points(Mode::Straight), vec![Point(2, 2)]);
+}
+
+#[test]
+fn sweep_matches_point_count() {
+    let mut state: u64 = 5;
+    let mut next = |n: u64| {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        ((state >> 33) % n) as i32
+    };
+    for _ in 0..20 {
+        let lines: Vec<Line> = (0..60)
+            .map(|_| {
+                let start = Point(next(40), next(40));
+                let length = next(30) + 1;
+                let (dx, dy) = [
+                    (1, 0),
+                    (0, 1),
+                    (1, 1),
+                    (1, -1),
+                    (-1, 0),
+                    (2, 1),
+                    (-1, 3),
+                    (3, 2),
+                ][next(8) as usize];
+                let length = if dx * dx + dy * dy > 2 {
+                    length / 3
+                } else {
+                    length
+                };
+                let end = Point(start.0 + dx * length, start.1 + dy * length);
+                Line { start, end }
+            })
+            .collect();
+        for mode in [Mode::Straight, Mode::Diagonal, Mode::AnySlope] {
+            let points: Vec<Point> = lines
+                .iter()
+                .flat_map(|line| line.all_points(mode))
+                .collect();
+            for min_cover in 1..4 {
+                assert_eq!(
+                    sweep_overlaps(&lines, mode, min_cover),
+                    count_overlaps(&points, min_cover) as u64
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn sweep_long_lines() {
+    let lines = Day05::parse(
+        "0,0 -> 4000000,0
+1000000,0 -> 6000000,0
+2500000,0 -> 2500000,9
+5000000,0 -> 5000000,3
+4999997,3 -> 5000000,0
+4999999,1 -> 5000001,3
+",
+    );
+    assert_eq!(sweep_overlaps(&lines, Mode::Straight, 2), 3000002);
+    assert_eq!(sweep_overlaps(&lines, Mode::Diagonal, 2), 3000004);
+}
+
+#[test]
+fn report_line_kinds() {
+    let lines = parse_lines("0,0 -> 4,0\n0,0 -> 0,4\n0,0 -> 4,4\n0,0 -> 4,2\n4,2 -> 4,2\n");
+    let report = overlap_report(&lines, Mode::Diagonal, 2);
+    assert_eq!(report.overlaps, 1);
+    assert_eq!(
+        report.kinds,
+        [
+            (LineKind::Point, 1, 0),
+            (LineKind::Horizontal, 1, 0),
+            (LineKind::Vertical, 1, 0),
+            (LineKind::Diagonal, 1, 0),
+            (LineKind::Sloped, 0, 1),
+        ]
+    );
+    assert_eq!(overlap_report(&lines, Mode::AnySlope, 2).overlaps, 2);
+}
+
+#[test]
+fn field_exports() {
+    let lines = parse_lines(include_str!("../example.txt"));
+    let field = Field::new(&lines, Mode::Diagonal);
+    assert_eq!(
+        field.to_ascii(),
+        "1.1....11.
+.111...2..
+..2.1.111.
+...1.2.2..
+.112313211
+...1.2....
+..1...1...
+.1.....1..
+1.......1.
+222111....
+"
+    );
+    assert_eq!(field.overlaps(2), 12);
+    assert_eq!(field.overlaps(3), 2);
+
+    let pgm = field.to_pgm();
+    assert!(pgm.starts_with(b"P5\n10 10\n255\n"));
+    assert_eq!(pgm.len(), 13 + 100);
+    assert_eq!(pgm[13..16], [85, 0, 85]);
+    let ppm = field.to_ppm(3);
+    assert_eq!(ppm.len(), 13 + 300);
+    // The first 3 is on the fifth row
+    assert_eq!(ppm[13 + 3 * 44..][..3], [255, 0, 0]);
+    assert_eq!(ppm[13 + 3 * 45..][..3], [106, 106, 106]);
+}
+
+utils::example_tests!(Day05);
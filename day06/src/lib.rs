@@ -0,0 +1,223 @@
+// each lanternfish created a new lanternfish every 7 days
+// each fish is a single number that represents days until new fish
+// new fish need extra 2 days to create a fish
+
+use utils::{BigUint, Solution};
+
+type Fish = u8;
+
+fn next_day(fish_at_days: &mut Vec<u128>) {
+    let fish_to_reproduce = fish_at_days[0];
+    fish_at_days.drain(0..1);
+    fish_at_days.push(fish_to_reproduce);
+    fish_at_days[6] += fish_to_reproduce;
+}
+
+// Steps through every day, as a check on fish_after
+pub fn simulate_days(fish: &[Fish], days: u32) -> u128 {
+    let collection = fish.to_owned();
+    let mut fish_at_days = parse_fish_to_days(collection);
+    for _ in 0..days {
+        next_day(&mut fish_at_days);
+    }
+    fish_at_days.iter().sum()
+}
+
+fn parse_fish_to_days(fish: Vec<Fish>) -> Vec<u128> {
+    let mut fish_at_days: Vec<u128> = vec![0; 9];
+    for f in fish {
+        fish_at_days[f as usize] += 1;
+    }
+    fish_at_days
+}
+
+// The arithmetic counting fish needs, so that one matrix power serves exact,
+// modular and arbitrary precision counts
+pub trait Count: Clone {
+    fn add(&self, other: &Self) -> Self;
+    fn mul(&self, other: &Self) -> Self;
+}
+
+impl Count for u128 {
+    fn add(&self, other: &Self) -> Self {
+        self.checked_add(*other).expect("Fish count overflowed")
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        self.checked_mul(*other).expect("Fish count overflowed")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Modular {
+    pub value: u64,
+    pub modulus: u64,
+}
+
+impl Modular {
+    pub fn new(value: u64, modulus: u64) -> Self {
+        Modular {
+            value: value % modulus,
+            modulus,
+        }
+    }
+}
+
+impl Count for Modular {
+    fn add(&self, other: &Self) -> Self {
+        let sum = self.value as u128 + other.value as u128;
+        Modular::new((sum % self.modulus as u128) as u64, self.modulus)
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        let product = self.value as u128 * other.value as u128;
+        Modular::new((product % self.modulus as u128) as u64, self.modulus)
+    }
+}
+
+impl Count for BigUint {
+    fn add(&self, other: &Self) -> Self {
+        self + other
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        self * other
+    }
+}
+
+type Matrix<T> = Vec<Vec<T>>;
+
+fn multiply<T: Count>(a: &Matrix<T>, b: &Matrix<T>) -> Matrix<T> {
+    (0..a.len())
+        .map(|row| {
+            (0..b[0].len())
+                .map(|column| {
+                    (1..b.len()).fold(a[row][0].mul(&b[0][column]), |sum, k| {
+                        sum.add(&a[row][k].mul(&b[k][column]))
+                    })
+                })
+                .collect()
+        })
+        .collect()
+}
+
+// How many fish there are after any number of days. A day maps the counts of
+// fish at each timer through a fixed matrix, so many days is that matrix
+// raised to a power, found by repeated squaring. lift turns small numbers into
+// the counting type.
+pub fn fish_after<T: Count>(fish: &[Fish], days: u64, lift: impl Fn(u64) -> T) -> T {
+    // transition[to][from]: timers count down, and a fish at 0 goes back to 6
+    // and adds a new fish at 8
+    let transition: Matrix<T> = (0..9)
+        .map(|to| {
+            (0..9)
+                .map(|from| lift((from == to + 1 || (from == 0 && (to == 6 || to == 8))) as u64))
+                .collect()
+        })
+        .collect();
+    let mut counts: Matrix<T> = parse_fish_to_days(fish.to_vec())
+        .into_iter()
+        .map(|count| vec![lift(count as u64)])
+        .collect();
+
+    let mut power = transition;
+    let mut days = days;
+    while days > 0 {
+        if days & 1 == 1 {
+            counts = multiply(&power, &counts);
+        }
+        days >>= 1;
+        if days > 0 {
+            power = multiply(&power, &power);
+        }
+    }
+    let mut counts = counts.into_iter().map(|mut row| row.remove(0));
+    let first = counts.next().unwrap();
+    counts.fold(first, |total, count| total.add(&count))
+}
+
+pub fn parse_fish(input: &str) -> Vec<Fish> {
+    input
+        .lines()
+        .next()
+        .unwrap()
+        .split(',')
+        .map(|c| c.parse().unwrap())
+        .collect()
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Vec<Fish>;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_fish(input)
+    }
+
+    fn part_one(fish: &Self::Input) -> String {
+        fish_after(fish, 80, u128::from).to_string()
+    }
+
+    fn part_two(fish: &Self::Input) -> String {
+        fish_after(fish, 256, u128::from).to_string()
+    }
+}
+
+#[test]
+fn simulate_days_test() {
+    let fish: Vec<Fish> = vec![3, 4, 3, 1, 2];
+    let count = simulate_days(&fish, 80);
+    assert_eq!(count, 5934);
+}
+
+#[test]
+fn matrix_matches_simulation() {
+    let fish: Vec<Fish> = vec![3, 4, 3, 1, 2];
+    for days in [0, 1, 2, 18, 80, 256, 400] {
+        let expected = simulate_days(&fish, days);
+        assert_eq!(fish_after(&fish, days as u64, u128::from), expected);
+        let big = fish_after(&fish, days as u64, BigUint::from);
+        assert_eq!(big.to_string(), expected.to_string());
+    }
+}
+
+#[test]
+fn modular_and_big_counts_agree() {
+    let fish: Vec<Fish> = vec![3, 4, 3, 1, 2];
+    let modulus = 1_000_000_000;
+    let modular = |days| fish_after(&fish, days, |n| Modular::new(n, modulus)).value;
+    assert_eq!(modular(256), 26984457539 % modulus);
+
+    let big = fish_after(&fish, 10_000, BigUint::from).to_string();
+    assert_eq!(big.len(), 380);
+    assert_eq!(big[big.len() - 9..], format!("{:09}", modular(10_000)));
+}
+
+#[test]
+fn modular_count_after_astronomically_many_days() {
+    let fish: Vec<Fish> = vec![3, 4, 3, 1, 2];
+    let days: u64 = 1_000_000_000_000_000;
+
+    // Stepping mod 10 must repeat a state, so the count on any day can be
+    // found from the first cycle
+    let mut state = parse_fish_to_days(fish.clone());
+    let mut seen = std::collections::HashMap::new();
+    let mut history = vec![];
+    while !seen.contains_key(&state) {
+        seen.insert(state.clone(), history.len() as u64);
+        history.push(state.iter().sum::<u128>() % 10);
+        next_day(&mut state);
+        state.iter_mut().for_each(|count| *count %= 10);
+    }
+    let start = seen[&state];
+    let cycle = history.len() as u64 - start;
+    let expected = history[(start + (days - start) % cycle) as usize];
+
+    let count = fish_after(&fish, days, |n| Modular::new(n, 10));
+    assert_eq!(count.value as u128, expected);
+}
+
+utils::example_tests!(Day06);
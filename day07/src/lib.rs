@@ -0,0 +1,74 @@
+use utils::Solution;
+
+fn part_one(crabs: &[i32]) -> i32 {
+    let mut sorted_crabs = crabs.to_owned();
+    sorted_crabs.sort_unstable();
+    let average_position = sorted_crabs[crabs.len() / 2];
+    sorted_crabs
+        .iter()
+        .map(|c| (c - average_position).abs())
+        .sum()
+}
+
+fn part_two(crabs: &[i32]) -> i32 {
+    let mut sorted_crabs = crabs.to_owned();
+    sorted_crabs.sort_unstable();
+    let mut costs: Vec<i32> = vec![];
+    let min = *sorted_crabs.first().unwrap();
+    let max = *sorted_crabs.last().unwrap();
+    for position in min..=max {
+        costs.push(
+            crabs
+                .iter()
+                .map(|crab| calculate_cost((position - crab).abs()))
+                .sum(),
+        );
+    }
+    *costs.iter().min().unwrap()
+}
+
+fn calculate_cost(distance: i32) -> i32 {
+    let mut acc: i32 = 0;
+    for i in 0..=distance {
+        acc += i;
+    }
+    acc
+}
+
+fn parse_crabs(line: &str) -> Vec<i32> {
+    line.split(',').map(|c| c.parse().unwrap()).collect()
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_crabs(input.lines().next().unwrap())
+    }
+
+    fn part_one(crabs: &Self::Input) -> String {
+        part_one(crabs).to_string()
+    }
+
+    fn part_two(crabs: &Self::Input) -> String {
+        part_two(crabs).to_string()
+    }
+}
+
+#[test]
+fn part_one_test() {
+    let crabs = parse_crabs(&String::from("16,1,2,0,4,2,7,1,2,14"));
+    assert_eq!(part_one(&crabs), 37);
+}
+
+#[test]
+fn part_two_test() {
+    let crabs = parse_crabs(&String::from("16,1,2,0,4,2,7,1,2,14"));
+    assert_eq!(part_two(&crabs), 168);
+}
+
+utils::example_tests!(Day07);
@@ -0,0 +1,101 @@
+use std::collections::HashSet;
+use utils::grid::Point;
+use utils::{Grid, Solution};
+
+pub struct Heightmap {
+    map: Grid<u32>,
+}
+
+impl Heightmap {
+    fn new(lines: &[String]) -> Self {
+        Heightmap {
+            map: Grid::from_digits(lines),
+        }
+    }
+
+    fn is_low_point(&self, point: Point) -> bool {
+        let val = self.map[point];
+        self.map.neighbours4(point).all(|n| self.map[n] > val)
+    }
+
+    fn get_basin_from_floor(&self, point: Point) -> Vec<Point> {
+        let mut basin: Vec<Point> = vec![];
+        let val = self.map[point];
+        // add start point
+        basin.push(point);
+        let mut adjacents: Vec<Point> = self.map.neighbours4(point).collect();
+        adjacents.retain(|&p| {
+            let v = self.map[p];
+            v > val && v < 9
+        });
+        for p in adjacents {
+            basin.append(&mut self.get_basin_from_floor(p));
+        }
+        dedupe_points(basin)
+    }
+}
+
+fn find_low_points(map: &Heightmap) -> Vec<u32> {
+    let mut low_points: Vec<u32> = vec![];
+    for point in map.map.points() {
+        if map.is_low_point(point) {
+            low_points.push(map.map[point]);
+        }
+    }
+    low_points
+}
+
+fn part_one(map: &Heightmap) -> u32 {
+    find_low_points(map).iter().map(|i| i + 1).sum()
+}
+
+fn part_two(map: &Heightmap) -> usize {
+    let mut points: Vec<Point> = map.map.points().collect();
+    // All points 9 or higher are not part of a basin
+    points.retain(|&p| map.map[p] < 9);
+
+    let mut basins: Vec<usize> = vec![];
+
+    loop {
+        let basin = map.get_basin_from_floor(points[0]);
+        points.retain(|p| !basin.contains(p));
+        basins.push(basin.len());
+        if points.is_empty() {
+            break;
+        }
+    }
+
+    basins.sort_unstable();
+    basins.reverse();
+    basins[0] * basins[1] * basins[2]
+}
+
+fn dedupe_points(points: Vec<Point>) -> Vec<Point> {
+    let mut set: HashSet<Point> = HashSet::new();
+    for p in points {
+        set.insert(p);
+    }
+    set.into_iter().collect()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Heightmap;
+
+    fn parse(input: &str) -> Self::Input {
+        Heightmap::new(&utils::lines(input))
+    }
+
+    fn part_one(heightmap: &Self::Input) -> String {
+        part_one(heightmap).to_string()
+    }
+
+    fn part_two(heightmap: &Self::Input) -> String {
+        part_two(heightmap).to_string()
+    }
+}
+
+utils::example_tests!(Day09);
@@ -0,0 +1,116 @@
+use utils::Solution;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Grid;
+
+    fn parse(input: &str) -> Self::Input {
+        Grid::from_lines(&utils::lines(input))
+    }
+
+    fn part_one(grid: &Self::Input) -> String {
+        part_one(&mut grid.clone()).to_string()
+    }
+
+    fn part_two(grid: &Self::Input) -> String {
+        part_two(&mut grid.clone()).to_string()
+    }
+}
+
+fn part_one(grid: &mut Grid) -> u32 {
+    let mut acc: u32 = 0;
+    for _ in 0..100 {
+        acc += grid.step();
+    }
+    acc
+}
+
+fn part_two(grid: &mut Grid) -> u32 {
+    let mut acc: u32 = 0;
+    loop {
+        acc += 1;
+        let flashes = grid.step();
+        if flashes == 100 {
+            return acc;
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Octopus {
+    value: u32,
+    flashed: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Grid {
+    grid: utils::Grid<Octopus>,
+}
+
+impl Grid {
+    fn from_lines(lines: &[String]) -> Self {
+        Grid {
+            grid: utils::Grid::from_digits(lines).map(|&value| Octopus {
+                value,
+                flashed: false,
+            }),
+        }
+    }
+
+    fn increment_all(&mut self) {
+        for octopus in self.grid.iter_mut() {
+            octopus.value += 1;
+        }
+    }
+
+    fn trigger_flashes(&mut self) -> bool {
+        let mut have_flashed = false;
+        for point in self.grid.points() {
+            let octopus = &mut self.grid[point];
+            if octopus.value > 9 && !octopus.flashed {
+                octopus.flashed = true;
+                have_flashed = true;
+                for neighbour in self.grid.neighbours8(point) {
+                    self.grid[neighbour].value += 1;
+                }
+            }
+        }
+        have_flashed
+    }
+
+    fn reset_flashes(&mut self) -> u32 {
+        let mut flashes: u32 = 0;
+        for octopus in self.grid.iter_mut() {
+            if octopus.flashed {
+                flashes += 1;
+                octopus.flashed = false;
+                octopus.value = 0;
+            }
+        }
+        flashes
+    }
+
+    fn step(&mut self) -> u32 {
+        self.increment_all();
+        while self.trigger_flashes() {}
+        self.reset_flashes()
+    }
+}
+
+#[test]
+fn part_one_example() {
+    let lines = vec![
+        String::from("11111"),
+        String::from("19991"),
+        String::from("19191"),
+        String::from("19991"),
+        String::from("11111"),
+    ];
+    let mut grid = Grid::from_lines(&lines);
+    assert_eq!(grid.step(), 9);
+}
+
+utils::example_tests!(Day11);
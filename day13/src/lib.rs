@@ -0,0 +1,165 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+use utils::parse::{self, ParseError, Parser};
+use utils::Solution;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+struct Point {
+    x: u32,
+    y: u32,
+}
+impl Point {
+    fn parse(p: &mut Parser) -> parse::Result<Self> {
+        let x = p.unsigned()?;
+        p.expect(",")?;
+        Ok(Point {
+            x,
+            y: p.unsigned()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Axis {
+    X,
+    Y,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Fold {
+    axis: Axis,
+    line: u32,
+}
+
+impl Fold {
+    fn parse(p: &mut Parser) -> parse::Result<Self> {
+        p.expect("fold along ")?;
+        let axis = p.one_of(&[("x", Axis::X), ("y", Axis::Y)])?;
+        p.expect("=")?;
+        Ok(Fold {
+            axis,
+            line: p.unsigned()?,
+        })
+    }
+}
+
+impl FromStr for Fold {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Parser::new(s).complete(Fold::parse)
+    }
+}
+
+fn fold_points(points: &mut Vec<Point>, fold: &Fold) {
+    for p in points {
+        match fold.axis {
+            Axis::Y => {
+                if p.y > fold.line {
+                    p.y = fold.line - (p.y - fold.line);
+                }
+            }
+            Axis::X => {
+                if p.x > fold.line {
+                    p.x = fold.line - (p.x - fold.line);
+                }
+            }
+        }
+    }
+}
+
+fn render_points(points: &[Point]) -> String {
+    let width = points.to_owned().iter().map(|p| p.x).max().unwrap();
+    let height = points.to_owned().iter().map(|p| p.y).max().unwrap();
+    let mut rendered = String::new();
+    for y in 0..=height {
+        for x in 0..=width {
+            if points.contains(&Point { x, y }) {
+                rendered.push('#');
+            } else {
+                rendered.push(' ');
+            }
+        }
+        rendered.push('\n');
+    }
+    rendered
+}
+
+fn dedupe_points(points: &mut Vec<Point>) {
+    let set: HashSet<_> = points.drain(..).collect();
+    points.extend(set);
+}
+
+pub struct Paper {
+    points: Vec<Point>,
+    folds: Vec<Fold>,
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Paper;
+
+    fn parse(input: &str) -> Self::Input {
+        Self::try_parse(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> parse::Result<Self::Input> {
+        let mut sections = Parser::new(input).sections();
+        let points = match sections.next() {
+            Some(section) => section.each_line(Point::parse)?,
+            None => vec![],
+        };
+        let folds = match sections.next() {
+            Some(section) => section.each_line(Fold::parse)?,
+            None => vec![],
+        };
+        Ok(Paper { points, folds })
+    }
+
+    fn part_one(paper: &Self::Input) -> String {
+        let mut points = paper.points.clone();
+        fold_points(&mut points, &paper.folds[0]);
+        dedupe_points(&mut points);
+        points.len().to_string()
+    }
+
+    fn part_two(paper: &Self::Input) -> String {
+        let mut points = paper.points.clone();
+        for fold in &paper.folds {
+            fold_points(&mut points, fold);
+            dedupe_points(&mut points);
+        }
+        render_points(&points)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn parse_fold_y() {
+        assert_eq!(
+            "fold along y=7".parse::<Fold>().unwrap(),
+            Fold {
+                axis: Axis::Y,
+                line: 7
+            }
+        );
+    }
+
+    #[test]
+    fn parse_fold_x() {
+        assert_eq!(
+            "fold along x=27".parse::<Fold>().unwrap(),
+            Fold {
+                axis: Axis::X,
+                line: 27
+            }
+        );
+    }
+
+    utils::example_tests!(Day13);
+}
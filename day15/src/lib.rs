@@ -0,0 +1,63 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use utils::grid::Point;
+use utils::{Grid, Solution};
+
+fn shortest_path(graph: &Grid<u32>, start: Point, end: Point) -> u32 {
+    let mut distance: Grid<Option<u32>> = graph.map(|_| None);
+    let mut predecessors: Grid<Option<Point>> = graph.map(|_| None);
+    distance[start] = Some(0);
+
+    let mut heap = BinaryHeap::new();
+    heap.push(Reverse((0, start)));
+
+    while let Some(Reverse((dist, pos))) = heap.pop() {
+        for neighbour in graph.neighbours4(pos) {
+            let new_dist = dist + graph[neighbour];
+            if distance[neighbour].is_none_or(|old_dist| new_dist < old_dist) {
+                heap.push(Reverse((new_dist, neighbour)));
+                distance[neighbour] = Some(new_dist);
+                predecessors[neighbour] = Some(pos);
+            }
+        }
+    }
+    distance[end].unwrap_or(u32::MAX)
+}
+
+fn extend_graph(graph: &Grid<u32>) -> Grid<u32> {
+    let (width, height) = (graph.width(), graph.height());
+    Grid::from_fn(width * 5, height * 5, |(x, y)| {
+        let mut new_val = graph[(x % width, y % height)];
+        for _ in 0..(x / width + y / height) {
+            new_val = if new_val == 9 { 1 } else { new_val + 1 };
+        }
+        new_val
+    })
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = Grid<u32>;
+
+    fn parse(input: &str) -> Self::Input {
+        Grid::from_digits(input.lines())
+    }
+
+    fn part_one(graph: &Self::Input) -> String {
+        shortest_path(graph, (0, 0), bottom_right(graph)).to_string()
+    }
+
+    fn part_two(graph: &Self::Input) -> String {
+        let extended_graph = extend_graph(graph);
+        shortest_path(&extended_graph, (0, 0), bottom_right(&extended_graph)).to_string()
+    }
+}
+
+fn bottom_right(graph: &Grid<u32>) -> Point {
+    (graph.width() - 1, graph.height() - 1)
+}
+
+utils::example_tests!(Day15);
@@ -0,0 +1,251 @@
+use utils::bits::BitReader;
+use utils::{BitVec, Solution};
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = Vec<Packet>;
+
+    fn parse(input: &str) -> Self::Input {
+        let binary = parse_hex(input.lines().next().unwrap());
+        parse_transmission(&mut binary.reader())
+    }
+
+    fn part_one(packets: &Self::Input) -> String {
+        let version_sum: u128 = packets.iter().map(|p| p.version).sum();
+        version_sum.to_string()
+    }
+
+    fn part_two(packets: &Self::Input) -> String {
+        packets[0].value.to_string()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Packet {
+    version: u128,
+    type_id: u128,
+    value: u128,
+}
+
+fn parse_transmission(reader: &mut BitReader) -> Vec<Packet> {
+    read_packet(reader).expect("Truncated transmission")
+}
+
+// The packet at the reader's position followed by all of its subpackets
+fn read_packet(reader: &mut BitReader) -> Option<Vec<Packet>> {
+    let mut packets: Vec<Packet> = vec![];
+
+    let version = reader.read_bits_u128(3)?;
+    let type_id = reader.read_bits_u128(3)?;
+
+    if type_id == 4 {
+        let mut value: u128 = 0;
+        loop {
+            let is_last = !reader.read_bit()?;
+            value = value.checked_mul(16)? | reader.read_bits_u128(4)?;
+            if is_last {
+                break;
+            }
+        }
+        packets.push(Packet {
+            version,
+            type_id,
+            value,
+        });
+    } else {
+        let length_type_id = reader.read_bit()?;
+        let mut subpackets: Vec<Packet> = vec![];
+        let mut subpacket_values: Vec<u128> = vec![];
+        match length_type_id {
+            false => {
+                let total_length_in_bits = reader.read_bits(15)? as usize;
+                let end = reader.position() + total_length_in_bits;
+                while reader.position() < end {
+                    let mut new_packets = read_packet(reader)?;
+                    subpacket_values.push(new_packets[0].value);
+                    subpackets.append(&mut new_packets);
+                }
+            }
+            true => {
+                let number_subpackets = reader.read_bits(11)?;
+                for _ in 0..number_subpackets {
+                    let mut new_packets = read_packet(reader)?;
+                    subpacket_values.push(new_packets[0].value);
+                    subpackets.append(&mut new_packets);
+                }
+            }
+        }
+
+        let value: u128 = match type_id {
+            0 => subpacket_values.iter().sum(),
+            1 => subpacket_values.iter().product(),
+            2 => *subpacket_values.iter().min().unwrap(),
+            3 => *subpacket_values.iter().max().unwrap(),
+            5 => {
+                if subpacket_values[0] > subpacket_values[1] {
+                    1
+                } else {
+                    0
+                }
+            }
+            6 => {
+                if subpacket_values[0] < subpacket_values[1] {
+                    1
+                } else {
+                    0
+                }
+            }
+            7 => {
+                if subpacket_values[0] == subpacket_values[1] {
+                    1
+                } else {
+                    0
+                }
+            }
+            _ => panic!("Invalid type_id {}", type_id),
+        };
+
+        packets.push(Packet {
+            version,
+            type_id,
+            value,
+        });
+        packets.append(&mut subpackets);
+    }
+
+    Some(packets)
+}
+
+fn parse_hex(line: &str) -> BitVec {
+    BitVec::from_hex(line.trim()).expect("Invalid hex transmission")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn literal_value_packet() {
+        let input = "D2FE28";
+        let packets = parse_transmission(&mut parse_hex(input).reader());
+        assert_eq!(
+            packets,
+            vec![Packet {
+                version: 6,
+                type_id: 4,
+                value: 2021
+            }]
+        );
+    }
+
+    #[test]
+    fn number_subpackets_operator() {
+        let input = "EE00D40C823060";
+        let packets = parse_transmission(&mut parse_hex(input).reader());
+        assert_eq!(
+            packets,
+            vec![
+                Packet {
+                    version: 7,
+                    type_id: 3,
+                    value: 3
+                },
+                Packet {
+                    version: 2,
+                    type_id: 4,
+                    value: 1
+                },
+                Packet {
+                    version: 4,
+                    type_id: 4,
+                    value: 2
+                },
+                Packet {
+                    version: 1,
+                    type_id: 4,
+                    value: 3
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn subpacket_length_operator() {
+        let input = "38006F45291200";
+        let packets = parse_transmission(&mut parse_hex(input).reader());
+        assert_eq!(
+            packets,
+            vec![
+                Packet {
+                    version: 1,
+                    type_id: 6,
+                    value: 1
+                },
+                Packet {
+                    version: 6,
+                    type_id: 4,
+                    value: 10
+                },
+                Packet {
+                    version: 2,
+                    type_id: 4,
+                    value: 20
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn nested_operators() {
+        let input = "8A004A801A8002F478";
+        let binary = parse_hex(input);
+        println!("Binary: {:?}", binary);
+        let packets = parse_transmission(&mut binary.reader());
+        assert_eq!(
+            packets,
+            vec![
+                Packet {
+                    version: 4,
+                    type_id: 2,
+                    value: 15
+                },
+                Packet {
+                    version: 1,
+                    type_id: 2,
+                    value: 15
+                },
+                Packet {
+                    version: 5,
+                    type_id: 2,
+                    value: 15
+                },
+                Packet {
+                    version: 6,
+                    type_id: 4,
+                    value: 15
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn product() {
+        let input = "04005AC33890";
+        let binary = parse_hex(input);
+        let packets = parse_transmission(&mut binary.reader());
+        assert_eq!(packets[0].value, 54);
+    }
+
+    #[test]
+    fn product_2() {
+        let input = "9C0141080250320F1802104A08";
+        let binary = parse_hex(input);
+        let packets = parse_transmission(&mut binary.reader());
+        assert_eq!(packets[0].value, 1);
+    }
+
+    utils::example_tests!(Day16);
+}
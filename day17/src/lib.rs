@@ -0,0 +1,109 @@
+use utils::parse::{self, Parser};
+use utils::Solution;
+
+type Target = (i32, i32, i32, i32);
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input = Target;
+
+    fn parse(input: &str) -> Self::Input {
+        Self::try_parse(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> parse::Result<Self::Input> {
+        let parser = Parser::new(input);
+        let parsed = match parser.lines().next() {
+            Some(line) => line.complete(parse_target)?,
+            None => return parser.error("expected `target area: `"),
+        };
+        utils::debug!("Target: {:?}", parsed);
+        Ok(parsed)
+    }
+
+    fn part_one(target: &Self::Input) -> String {
+        let successfull_velocities = successful_velocities(*target);
+        let highest_y = successfull_velocities.iter().map(|v| v.2).max().unwrap();
+        highest_y.to_string()
+    }
+
+    fn part_two(target: &Self::Input) -> String {
+        successful_velocities(*target).len().to_string()
+    }
+}
+
+// target area: x=20..30, y=-10..-5
+fn parse_target(p: &mut Parser) -> parse::Result<Target> {
+    p.expect("target area: ")?;
+    let (x1, x2) = p.key_value("x", |p| p.range(Parser::signed))?;
+    p.expect(", ")?;
+    let (y1, y2) = p.key_value("y", |p| p.range(Parser::signed))?;
+    Ok((x1, x2, y1, y2))
+}
+
+fn successful_velocities(target: Target) -> Vec<(i32, i32, i32)> {
+    let mut successfull_velocities: Vec<(i32, i32, i32)> = vec![];
+
+    for x in 0..1000 {
+        for y in -1000..1000 {
+            if let VelocityResult::Success(highest) = try_velocity(&(x, y), target) {
+                successfull_velocities.push((x, y, highest));
+            }
+        }
+    }
+
+    utils::debug!("{} successful velocities", successfull_velocities.len());
+    for v in &successfull_velocities {
+        utils::trace!("Velocity {},{} reaches {}", v.0, v.1, v.2);
+    }
+
+    successfull_velocities
+}
+
+#[derive(Debug)]
+enum VelocityResult {
+    Success(i32),
+    Fail,
+}
+
+fn try_velocity(velocity: &(i32, i32), target: Target) -> VelocityResult {
+    let mut position = (0i32, 0i32);
+    let mut velocity = *velocity;
+
+    let mut highest_y = 0i32;
+
+    loop {
+        // Step
+        position.0 += velocity.0;
+        position.1 += velocity.1;
+        // println!("Moved to {},{}", position.0, position.1);
+        if velocity.0 > 0 {
+            velocity.0 -= 1;
+        } else if velocity.0 < -0 {
+            velocity.0 += 1;
+        }
+        velocity.1 -= 1;
+        if position.1 > highest_y {
+            highest_y = position.1
+        }
+
+        // Check if in target area
+        if position.0 <= target.1
+            && position.0 >= target.0
+            && position.1 >= target.2
+            && position.1 <= target.3
+        {
+            // println!("Landed in target at {},{}", position.0, position.1);
+            return VelocityResult::Success(highest_y);
+        }
+        if position.0 > target.1 || position.1 < target.2 {
+            // println!("Out of bounds");
+            return VelocityResult::Fail;
+        }
+    }
+}
+
+utils::example_tests!(Day17);
@@ -0,0 +1,108 @@
+use std::fmt::Display;
+use utils::{BitVec, Grid, Solution};
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input = Map;
+
+    fn parse(input: &str) -> Self::Input {
+        Map::new(&utils::lines(input))
+    }
+
+    fn part_one(map: &Self::Input) -> String {
+        simulate_enhancements(map.clone(), 2).to_string()
+    }
+
+    fn part_two(map: &Self::Input) -> String {
+        simulate_enhancements(map.clone(), 50).to_string()
+    }
+}
+
+fn simulate_enhancements(map: Map, qty: usize) -> u32 {
+    let mut map = map;
+    for _ in 0..qty {
+        map.enhance();
+    }
+    map.count_lights()
+}
+
+// Lit = true
+// Dark = false
+
+#[derive(Clone)]
+pub struct Map {
+    algorithm: BitVec,
+    values: Grid<bool>,
+    outside_value: bool,
+}
+
+impl Map {
+    fn new(lines: &[String]) -> Self {
+        let algorithm: BitVec = lines[0].chars().map(|c| c == '#').collect();
+        assert_eq!(algorithm.len(), 512, "algorithm must be 512 bits");
+        let values = Grid::from_chars(lines.iter().skip(2), |c| c == '#');
+        Map {
+            algorithm,
+            values,
+            outside_value: false,
+        }
+    }
+
+    fn get_point(&self, x: isize, y: isize) -> bool {
+        if x < 0 || y < 0 {
+            return self.outside_value;
+        }
+        self.values
+            .get((x as usize, y as usize))
+            .copied()
+            .unwrap_or(self.outside_value)
+    }
+
+    fn get_new_point_value(&self, x: isize, y: isize) -> bool {
+        let neighbourhood: BitVec = (y - 1..y + 2)
+            .flat_map(|y| (x - 1..x + 2).map(move |x| (x, y)))
+            .map(|(x, y)| self.get_point(x, y))
+            .collect();
+        let number = neighbourhood.to_u64().unwrap();
+        self.algorithm.get(number as usize).unwrap()
+    }
+
+    fn enhance(&mut self) {
+        let width = self.values.width();
+        let height = self.values.height();
+        self.values = Grid::from_fn(width + 2, height + 2, |(x, y)| {
+            self.get_new_point_value(x as isize - 1, y as isize - 1)
+        });
+        match self.outside_value {
+            true => self.outside_value = self.algorithm.get(511).unwrap(),
+            false => self.outside_value = self.algorithm.get(0).unwrap(),
+        };
+    }
+
+    fn count_lights(&self) -> u32 {
+        self.values.iter().filter(|&&val| val).count() as u32
+    }
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.values.rows() {
+            for value in row {
+                write!(f, "{}", if *value { '#' } else { '.' })?
+            }
+            writeln!(f)?
+        }
+        writeln!(
+            f,
+            "Outside is {}",
+            if self.outside_value { "light" } else { "dark" }
+        )?;
+        writeln!(f, "Lights: {}", self.count_lights())?;
+        Ok(())
+    }
+}
+
+utils::example_tests!(Day20);
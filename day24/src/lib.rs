@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use utils::parse::{self, ParseError, Parser};
+use utils::Solution;
+type Cache = HashMap<(i64, usize), Option<i64>>;
+
+#[derive(Clone, Copy)]
+pub enum Source {
+    Reg(usize),
+    Val(i64),
+}
+
+impl Source {
+    fn val(&self, regs: &[i64; 4]) -> i64 {
+        match *self {
+            Self::Reg(i) => regs[i],
+            Self::Val(v) => v,
+        }
+    }
+}
+
+impl Source {
+    fn parse(p: &mut Parser) -> parse::Result<Self> {
+        match p.peek() {
+            Some('w'..='z') => Ok(Self::Reg(register(p)?)),
+            _ => Ok(Self::Val(p.signed()?)),
+        }
+    }
+}
+
+impl FromStr for Source {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Parser::new(s).complete(Source::parse)
+    }
+}
+
+fn register(p: &mut Parser) -> parse::Result<usize> {
+    p.one_of(&[("w", 0), ("x", 1), ("y", 2), ("z", 3)])
+}
+
+#[derive(Clone, Copy)]
+pub enum Instruction {
+    Input(usize),
+    Add(usize, Source),
+    Multiply(usize, Source),
+    Divide(usize, Source),
+    Modulo(usize, Source),
+    Equal(usize, Source),
+}
+
+impl Instruction {
+    fn parse(p: &mut Parser) -> parse::Result<Self> {
+        let start = *p;
+        let op = p.word()?;
+        p.expect(" ")?;
+        let dest = register(p)?;
+        if op == "inp" {
+            return Ok(Instruction::Input(dest));
+        }
+        let make = match op {
+            "add" => Instruction::Add,
+            "mul" => Instruction::Multiply,
+            "div" => Instruction::Divide,
+            "mod" => Instruction::Modulo,
+            "eql" => Instruction::Equal,
+            _ => return start.error(format!("unknown instruction `{}`", op)),
+        };
+        p.expect(" ")?;
+        Ok(make(dest, Source::parse(p)?))
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Parser::new(s).complete(Instruction::parse)
+    }
+}
+
+fn find_model_number(
+    cache: &mut Cache,
+    blocks: &[Vec<Instruction>],
+    block: usize,
+    z: i64,
+    range: &[i64; 9],
+) -> Option<i64> {
+    if let Some(&answer) = cache.get(&(z, block)) {
+        return answer;
+    }
+
+    for &digit in range {
+        let mut regs = [digit, 0, 0, z];
+        for &inst in &blocks[block] {
+            match inst {
+                Instruction::Add(a, b) => regs[a] += b.val(&regs),
+                Instruction::Multiply(a, b) => regs[a] *= b.val(&regs),
+                Instruction::Divide(a, b) => regs[a] /= b.val(&regs),
+                Instruction::Modulo(a, b) => regs[a] %= b.val(&regs),
+                Instruction::Equal(a, b) => regs[a] = (regs[a] == b.val(&regs)) as i64,
+                Instruction::Input(_) => unreachable!(),
+            }
+        }
+        let z = regs[3];
+        if block + 1 == blocks.len() {
+            if z == 0 {
+                cache.insert((z, block), Some(digit));
+                return Some(digit);
+            }
+            continue;
+        }
+        if let Some(best) = find_model_number(cache, blocks, block + 1, z, range) {
+            cache.insert((z, block), Some(best * 10 + digit));
+            return Some(best * 10 + digit);
+        }
+    }
+
+    cache.insert((z, block), None);
+    None
+}
+
+fn solve(blocks: &[Vec<Instruction>], biggest: bool) -> String {
+    let mut digits = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+    if biggest {
+        digits.reverse();
+    }
+    let answer = find_model_number(&mut Cache::new(), blocks, 0, 0, &digits).unwrap();
+    answer.to_string().chars().rev().collect()
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+
+    type Input = Vec<Vec<Instruction>>;
+
+    fn parse(input: &str) -> Self::Input {
+        Self::try_parse(input).unwrap()
+    }
+
+    fn try_parse(input: &str) -> parse::Result<Self::Input> {
+        let instructions = Parser::new(input).each_line(Instruction::parse)?;
+        Ok(instructions
+            .chunks(18)
+            .map(|c| c.iter().skip(1).copied().collect())
+            .collect())
+    }
+
+    fn part_one(blocks: &Self::Input) -> String {
+        solve(blocks, true)
+    }
+
+    fn part_two(blocks: &Self::Input) -> String {
+        solve(blocks, false)
+    }
+}
+
+utils::example_tests!(Day24);
@@ -0,0 +1,125 @@
+use std::fmt;
+use utils::{Grid, Solution};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Space {
+    Empty,
+    SeaCucumber(Direction),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Right,
+    Down,
+}
+
+#[derive(Debug)]
+pub struct ParseSpaceError;
+
+impl Space {
+    fn from_char(c: &char) -> Result<Self, ParseSpaceError> {
+        match c {
+            '.' => Ok(Space::Empty),
+            '>' => Ok(Space::SeaCucumber(Direction::Right)),
+            'v' => Ok(Space::SeaCucumber(Direction::Down)),
+            _ => Err(ParseSpaceError),
+        }
+    }
+}
+
+impl fmt::Display for Space {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Space::Empty => '.',
+            Space::SeaCucumber(Direction::Right) => '>',
+            Space::SeaCucumber(Direction::Down) => 'v',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+#[derive(Clone)]
+pub struct Map {
+    grid: Grid<Space>,
+}
+
+impl Map {
+    fn from_lines(lines: &[String]) -> Result<Self, ParseSpaceError> {
+        Ok(Map {
+            grid: Grid::try_from_chars(lines, |c| Space::from_char(&c))?,
+        })
+    }
+
+    fn log(&self) {
+        utils::debug!("Map:\n{}", self.grid);
+    }
+
+    // Coordinates wrap around the edges of the map
+    pub fn get(&self, column: isize, row: isize) -> &Space {
+        &self.grid[self.grid.wrapping_offset((0, 0), (column, row))]
+    }
+
+    fn next_step(&mut self) -> bool {
+        let mut did_move = false;
+
+        let mut moved_right = self.grid.clone();
+        for (point, space) in self.grid.cells() {
+            let next = self.grid.wrapping_offset(point, (1, 0));
+            if *space == Space::SeaCucumber(Direction::Right) && self.grid[next] == Space::Empty {
+                moved_right[next] = Space::SeaCucumber(Direction::Right);
+                moved_right[point] = Space::Empty;
+                did_move = true;
+            }
+        }
+
+        let mut moved_down = moved_right.clone();
+        for (point, space) in moved_right.cells() {
+            let next = moved_right.wrapping_offset(point, (0, 1));
+            if *space == Space::SeaCucumber(Direction::Down) && moved_right[next] == Space::Empty {
+                moved_down[next] = Space::SeaCucumber(Direction::Down);
+                moved_down[point] = Space::Empty;
+                did_move = true;
+            }
+        }
+        self.grid = moved_down;
+        did_move
+    }
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+
+    type Input = Map;
+
+    fn parse(input: &str) -> Self::Input {
+        Map::from_lines(&utils::lines(input)).unwrap()
+    }
+
+    fn part_one(map: &Self::Input) -> String {
+        let mut map = map.clone();
+        map.log();
+        let mut steps = 1usize;
+        while map.next_step() {
+            steps += 1;
+        }
+        steps.to_string()
+    }
+
+    // There is no puzzle for day 25 part two
+    fn part_two(_map: &Self::Input) -> String {
+        String::from("Merry Christmas")
+    }
+}
+
+#[test]
+fn get_wraps_around() {
+    let map = Day25::parse(".>\nv.\n");
+    assert!(*map.get(1, 0) == Space::SeaCucumber(Direction::Right));
+    assert!(*map.get(-1, 2) == Space::SeaCucumber(Direction::Right));
+    assert!(*map.get(2, 1) == Space::SeaCucumber(Direction::Down));
+    assert!(*map.get(3, -1) == Space::Empty);
+}
+
+utils::example_tests!(Day25);
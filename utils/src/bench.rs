@@ -0,0 +1,153 @@
+use crate::solution::{Answers, Timings};
+use crate::Error;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort_unstable();
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+        Stats {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Benchmark {
+    pub day: u8,
+    pub runs: usize,
+    pub parse: Stats,
+    pub part_one: Stats,
+    pub part_two: Stats,
+}
+
+impl Benchmark {
+    fn phases(&self) -> [(&'static str, &Stats); 3] {
+        [
+            ("parse", &self.parse),
+            ("part_one", &self.part_one),
+            ("part_two", &self.part_two),
+        ]
+    }
+}
+
+pub fn bench<F>(day: u8, solver: F, input: &str, runs: usize) -> Benchmark
+where
+    F: Fn(&str) -> (Answers, Timings),
+{
+    let mut parse = Vec::with_capacity(runs);
+    let mut part_one = Vec::with_capacity(runs);
+    let mut part_two = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let (_, timings) = solver(input);
+        parse.push(timings.parse);
+        part_one.push(timings.part_one);
+        part_two.push(timings.part_two);
+    }
+    Benchmark {
+        day,
+        runs,
+        parse: Stats::from_samples(parse),
+        part_one: Stats::from_samples(part_one),
+        part_two: Stats::from_samples(part_two),
+    }
+}
+
+pub fn render(benchmarks: &[Benchmark]) -> String {
+    let mut out = format!(
+        "{:>3}  {:<8}  {:>12}  {:>12}  {:>12}\n",
+        "Day", "Phase", "Min", "Median", "Max"
+    );
+    for benchmark in benchmarks {
+        for (phase, stats) in benchmark.phases() {
+            out.push_str(&format!(
+                "{:>3}  {:<8}  {:>12}  {:>12}  {:>12}\n",
+                benchmark.day,
+                phase,
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.max)
+            ));
+        }
+    }
+    out
+}
+
+// One JSON object per day and phase, with times in nanoseconds
+pub fn to_json(benchmarks: &[Benchmark]) -> String {
+    let records: Vec<String> = benchmarks
+        .iter()
+        .flat_map(|benchmark| {
+            benchmark.phases().map(|(phase, stats)| {
+                format!(
+                    "  {{\"day\": {}, \"phase\": \"{}\", \"runs\": {}, \"min_ns\": {}, \"median_ns\": {}, \"max_ns\": {}}}",
+                    benchmark.day,
+                    phase,
+                    benchmark.runs,
+                    stats.min.as_nanos(),
+                    stats.median.as_nanos(),
+                    stats.max.as_nanos()
+                )
+            })
+        })
+        .collect();
+    format!("[\n{}\n]\n", records.join(",\n"))
+}
+
+pub fn write_report(path: &str, benchmarks: &[Benchmark]) -> Result<(), Error> {
+    crate::write_file(path, to_json(benchmarks))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(ms: &[u64]) -> Vec<Duration> {
+        ms.iter().map(|&m| Duration::from_millis(m)).collect()
+    }
+
+    #[test]
+    fn stats_odd_samples() {
+        let stats = Stats::from_samples(millis(&[5, 1, 3]));
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.max, Duration::from_millis(5));
+    }
+
+    #[test]
+    fn stats_even_samples() {
+        let stats = Stats::from_samples(millis(&[4, 1, 2, 8]));
+        assert_eq!(stats.median, Duration::from_millis(3));
+    }
+
+    #[test]
+    fn json_report() {
+        let stats = Stats::from_samples(millis(&[1]));
+        let benchmark = Benchmark {
+            day: 7,
+            runs: 1,
+            parse: stats,
+            part_one: stats,
+            part_two: stats,
+        };
+        let json = to_json(&[benchmark]);
+        assert!(json.starts_with(
+            "[\n  {\"day\": 7, \"phase\": \"parse\", \"runs\": 1, \"min_ns\": 1000000"
+        ));
+        assert_eq!(json.matches("\"day\": 7").count(), 3);
+    }
+}
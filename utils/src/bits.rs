@@ -0,0 +1,246 @@
+use std::fmt;
+use std::iter::FromIterator;
+use std::ops::Range;
+
+// Bits packed most significant first, so reading n bits from the start gives
+// the number they spell out in binary
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct BitVec {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitVec {
+    pub fn new() -> Self {
+        BitVec::default()
+    }
+
+    // Four bits per hex digit, either case
+    pub fn from_hex(s: &str) -> Option<Self> {
+        let mut bits = BitVec::new();
+        for c in s.chars() {
+            let digit = c.to_digit(16)?;
+            for shift in (0..4).rev() {
+                bits.push(digit >> shift & 1 == 1);
+            }
+        }
+        Some(bits)
+    }
+
+    pub fn from_bin_str(s: &str) -> Option<Self> {
+        s.chars()
+            .map(|c| match c {
+                '0' => Some(false),
+                '1' => Some(true),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, index: usize) -> Option<bool> {
+        if index < self.len {
+            Some(self.words[index / 64] >> (63 - index % 64) & 1 == 1)
+        } else {
+            None
+        }
+    }
+
+    pub fn set(&mut self, index: usize, bit: bool) {
+        assert!(index < self.len, "bit index out of bounds");
+        let mask = 1 << (63 - index % 64);
+        if bit {
+            self.words[index / 64] |= mask;
+        } else {
+            self.words[index / 64] &= !mask;
+        }
+    }
+
+    pub fn push(&mut self, bit: bool) {
+        if self.len.is_multiple_of(64) {
+            self.words.push(0);
+        }
+        self.len += 1;
+        self.set(self.len - 1, bit);
+    }
+
+    // The n bits from start as a number, or None if they run past the end or
+    // don't fit in a u64
+    pub fn read_bits(&self, start: usize, n: usize) -> Option<u64> {
+        if n > 64 || start.checked_add(n)? > self.len {
+            return None;
+        }
+        if n == 0 {
+            return Some(0);
+        }
+        let (word, offset) = (start / 64, start % 64);
+        let mut value = self.words[word] << offset;
+        if offset + n > 64 {
+            value |= self.words[word + 1] >> (64 - offset);
+        }
+        Some(value >> (64 - n))
+    }
+
+    pub fn read_bits_u128(&self, start: usize, n: usize) -> Option<u128> {
+        if n <= 64 {
+            return self.read_bits(start, n).map(u128::from);
+        }
+        if n > 128 {
+            return None;
+        }
+        let high = self.read_bits(start, n - 64)?;
+        let low = self.read_bits(start + n - 64, 64)?;
+        Some(u128::from(high) << 64 | u128::from(low))
+    }
+
+    // All of the bits as a number, if there are no more than 64
+    pub fn to_u64(&self) -> Option<u64> {
+        self.read_bits(0, self.len)
+    }
+
+    pub fn slice(&self, range: Range<usize>) -> BitVec {
+        assert!(range.end <= self.len, "bit range out of bounds");
+        range.map(|i| self.get(i).unwrap()).collect()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.len).map(move |i| self.get(i).unwrap())
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn reader(&self) -> BitReader<'_> {
+        BitReader { bits: self, pos: 0 }
+    }
+}
+
+impl FromIterator<bool> for BitVec {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let mut bits = BitVec::new();
+        bits.extend(iter);
+        bits
+    }
+}
+
+impl Extend<bool> for BitVec {
+    fn extend<I: IntoIterator<Item = bool>>(&mut self, iter: I) {
+        for bit in iter {
+            self.push(bit);
+        }
+    }
+}
+
+impl fmt::Display for BitVec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for bit in self.iter() {
+            write!(f, "{}", bit as u8)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for BitVec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "BitVec({})", self)
+    }
+}
+
+// Reads a BitVec front to back, as packet decoders do
+#[derive(Debug, Clone)]
+pub struct BitReader<'a> {
+    bits: &'a BitVec,
+    pos: usize,
+}
+
+impl BitReader<'_> {
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.bits.len() - self.pos
+    }
+
+    pub fn read_bit(&mut self) -> Option<bool> {
+        let bit = self.bits.get(self.pos)?;
+        self.pos += 1;
+        Some(bit)
+    }
+
+    pub fn read_bits(&mut self, n: usize) -> Option<u64> {
+        let value = self.bits.read_bits(self.pos, n)?;
+        self.pos += n;
+        Some(value)
+    }
+
+    pub fn read_bits_u128(&mut self, n: usize) -> Option<u128> {
+        let value = self.bits.read_bits_u128(self.pos, n)?;
+        self.pos += n;
+        Some(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_hex_and_bin_str() {
+        let bits = BitVec::from_hex("D2FE28").unwrap();
+        assert_eq!(bits.to_string(), "110100101111111000101000");
+        assert_eq!(BitVec::from_bin_str("110100101111111000101000"), Some(bits));
+        assert_eq!(BitVec::from_hex("D2G"), None);
+        assert_eq!(BitVec::from_bin_str("012"), None);
+    }
+
+    #[test]
+    fn read_bits_across_words() {
+        let bits: BitVec = (0..200).map(|i| i % 3 == 0).collect();
+        let expected = |start: usize, n: usize| {
+            (start..start + n).fold(0u128, |acc, i| acc << 1 | (i % 3 == 0) as u128)
+        };
+        for start in [0, 1, 60, 63, 64, 100] {
+            for n in [0, 1, 5, 33, 64] {
+                assert_eq!(bits.read_bits(start, n), Some(expected(start, n) as u64));
+            }
+            assert_eq!(bits.read_bits_u128(start, 100), Some(expected(start, 100)));
+        }
+    }
+
+    #[test]
+    fn reads_are_checked() {
+        let bits = BitVec::from_bin_str("1011").unwrap();
+        assert_eq!(bits.to_u64(), Some(11));
+        assert_eq!(bits.read_bits(2, 3), None);
+        assert_eq!(bits.read_bits(0, 65), None);
+        let wide: BitVec = (0..65).map(|_| true).collect();
+        assert_eq!(wide.to_u64(), None);
+        assert_eq!(wide.read_bits_u128(0, 65), Some((1 << 65) - 1));
+    }
+
+    #[test]
+    fn reader_slice_and_set() {
+        let mut bits = BitVec::from_hex("38006F").unwrap();
+        let mut reader = bits.reader();
+        assert_eq!(reader.read_bits(3), Some(1));
+        assert_eq!(reader.read_bits(3), Some(6));
+        assert_eq!(reader.read_bit(), Some(false));
+        assert_eq!((reader.position(), reader.remaining()), (7, 17));
+        assert_eq!(bits.slice(7..22).to_u64(), Some(27));
+        bits.set(2, false);
+        assert_eq!(bits.count_ones(), 8);
+        assert_eq!(
+            bits.iter().take(4).collect::<Vec<_>>(),
+            [false, false, false, true]
+        );
+    }
+}
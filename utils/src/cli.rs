@@ -0,0 +1,229 @@
+use crate::output::Format;
+use crate::Error;
+use std::str::FromStr;
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Options {
+    pub positional: Vec<String>,
+    pub bench: Option<usize>,
+    pub report: Option<String>,
+    pub verify: bool,
+    pub format: Format,
+    pub verbose: u8,
+}
+
+impl Options {
+    pub fn from_env() -> Result<Self, Error> {
+        Options::parse(std::env::args().skip(1))
+    }
+
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self, Error> {
+        let mut options = Options::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--bench" => {
+                    let runs = args.next().ok_or(Error::MissingArgument("--bench runs"))?;
+                    match runs.parse() {
+                        Ok(runs) if runs > 0 => options.bench = Some(runs),
+                        _ => return Err(Error::InvalidArgument(format!("--bench {}", runs))),
+                    }
+                }
+                "--report" => {
+                    let path = args.next().ok_or(Error::MissingArgument("--report path"))?;
+                    options.report = Some(path);
+                }
+                "--verify" => options.verify = true,
+                "--format" => {
+                    let format = args.next().ok_or(Error::MissingArgument("--format"))?;
+                    options.format = format
+                        .parse()
+                        .map_err(|_| Error::InvalidArgument(format!("--format {}", format)))?;
+                }
+                // -v, -vv, ...
+                _ if arg.len() > 1
+                    && arg.starts_with('-')
+                    && arg[1..].chars().all(|c| c == 'v') =>
+                {
+                    options.verbose = options.verbose.saturating_add((arg.len() - 1) as u8);
+                }
+                _ if arg.starts_with("--") => return Err(Error::InvalidArgument(arg)),
+                _ => options.positional.push(arg),
+            }
+        }
+        Ok(options)
+    }
+
+    // Falls back to stdin when no path is given
+    pub fn input_path(&self) -> &str {
+        self.positional
+            .first()
+            .map(String::as_str)
+            .unwrap_or(crate::STDIN)
+    }
+}
+
+// The arguments of a day's own subcommand: the flags it knows about and at
+// most one input path, which defaults to stdin
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct CommandArgs {
+    path: Option<String>,
+    switches: Vec<&'static str>,
+    values: Vec<(&'static str, String)>,
+}
+
+impl CommandArgs {
+    // Switches stand alone, options take the next argument as their value
+    pub fn parse(
+        args: &[String],
+        switches: &[&'static str],
+        options: &[&'static str],
+    ) -> Result<Self, Error> {
+        let mut parsed = CommandArgs::default();
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            if let Some(&switch) = switches.iter().find(|&&s| s == arg) {
+                parsed.switches.push(switch);
+            } else if let Some(&option) = options.iter().find(|&&o| o == arg) {
+                let value = args.next().ok_or(Error::MissingArgument(option))?;
+                parsed.values.push((option, value.clone()));
+            } else if arg.starts_with("--") || parsed.path.is_some() {
+                return Err(Error::InvalidArgument(arg.clone()));
+            } else {
+                parsed.path = Some(arg.clone());
+            }
+        }
+        Ok(parsed)
+    }
+
+    pub fn input_path(&self) -> &str {
+        self.path.as_deref().unwrap_or(crate::STDIN)
+    }
+
+    pub fn switch(&self, name: &str) -> bool {
+        self.switches.contains(&name)
+    }
+
+    // The last value given for an option
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.values
+            .iter()
+            .rev()
+            .find(|(option, _)| *option == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    // None when the option wasn't given, an error when its value won't parse
+    pub fn parse_value<T: FromStr>(&self, name: &str) -> Result<Option<T>, Error> {
+        self.value(name)
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|_| Error::InvalidArgument(format!("{} {}", name, value)))
+            })
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Options, Error> {
+        Options::parse(args.iter().map(|a| a.to_string()))
+    }
+
+    #[test]
+    fn parse_bench_options() {
+        let options = parse(&["input.txt", "--bench", "10", "--report", "out.json"]).unwrap();
+        assert_eq!(options.input_path(), "input.txt");
+        assert_eq!(options.bench, Some(10));
+        assert_eq!(options.report.as_deref(), Some("out.json"));
+        assert!(!options.verify);
+    }
+
+    #[test]
+    fn parse_verify_flag() {
+        let options = parse(&["--verify", "example.txt"]).unwrap();
+        assert!(options.verify);
+        assert_eq!(options.input_path(), "example.txt");
+    }
+
+    #[test]
+    fn input_defaults_to_stdin() {
+        assert_eq!(parse(&[]).unwrap().input_path(), "-");
+        assert_eq!(parse(&["-", "--verify"]).unwrap().input_path(), "-");
+    }
+
+    #[test]
+    fn reject_bad_bench_runs() {
+        assert!(matches!(
+            parse(&["input.txt", "--bench", "0"]),
+            Err(Error::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            parse(&["input.txt", "--bench"]),
+            Err(Error::MissingArgument(_))
+        ));
+    }
+
+    #[test]
+    fn parse_format() {
+        assert_eq!(parse(&["input.txt"]).unwrap().format, Format::Text);
+        assert_eq!(parse(&["--format", "json"]).unwrap().format, Format::Json);
+        assert!(matches!(
+            parse(&["--format", "xml"]),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn count_verbosity() {
+        assert_eq!(parse(&["input.txt"]).unwrap().verbose, 0);
+        assert_eq!(parse(&["-v", "input.txt", "-vv"]).unwrap().verbose, 3);
+        assert_eq!(parse(&["-"]).unwrap().input_path(), "-");
+    }
+
+    #[test]
+    fn reject_unknown_flags() {
+        assert!(matches!(parse(&["--fast"]), Err(Error::InvalidArgument(_))));
+    }
+
+    fn command(args: &[&str]) -> Result<CommandArgs, Error> {
+        let args: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+        CommandArgs::parse(&args, &["--diagonals"], &["--window", "--svg"])
+    }
+
+    #[test]
+    fn parse_command_args() {
+        let args = command(&["--window", "3", "input.txt", "--diagonals"]).unwrap();
+        assert_eq!(args.input_path(), "input.txt");
+        assert!(args.switch("--diagonals"));
+        assert_eq!(args.parse_value::<usize>("--window").unwrap(), Some(3));
+        assert_eq!(args.value("--svg"), None);
+
+        let args = command(&["--window", "three"]).unwrap();
+        assert_eq!(args.input_path(), "-");
+        assert!(!args.switch("--diagonals"));
+        assert!(matches!(
+            args.parse_value::<usize>("--window"),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn reject_bad_command_args() {
+        assert!(matches!(
+            command(&["--svg"]),
+            Err(Error::MissingArgument("--svg"))
+        ));
+        assert!(matches!(
+            command(&["--csv", "out.csv"]),
+            Err(Error::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            command(&["a.txt", "b.txt"]),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+}
@@ -0,0 +1,89 @@
+use crate::parse::ParseError;
+use std::fmt;
+use std::io;
+
+#[derive(Debug)]
+pub enum Error {
+    MissingArgument(&'static str),
+    InvalidArgument(String),
+    Io {
+        path: String,
+        source: io::Error,
+    },
+    EmptyInput {
+        path: String,
+    },
+    InvalidUtf8 {
+        path: String,
+    },
+    InvalidInput {
+        path: String,
+        line: usize,
+        column: Option<usize>,
+        message: String,
+    },
+    InvalidAnswers {
+        path: String,
+        line: usize,
+        message: String,
+    },
+    MissingAnswers {
+        path: String,
+        section: String,
+    },
+    VerificationFailed,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingArgument(name) => write!(f, "Missing argument: {}", name),
+            Error::InvalidArgument(arg) => write!(f, "Invalid argument: {}", arg),
+            Error::Io { path, source } => write!(f, "{}: {}", path, source),
+            Error::EmptyInput { path } => write!(f, "Input {} is empty", path),
+            Error::InvalidUtf8 { path } => write!(f, "Input {} is not valid UTF-8", path),
+            Error::InvalidInput {
+                path,
+                line,
+                column: Some(column),
+                message,
+            } => write!(f, "{}:{}:{}: {}", path, line, column, message),
+            Error::InvalidInput {
+                path,
+                line,
+                column: None,
+                message,
+            } => write!(f, "{}:{}: {}", path, line, message),
+            Error::InvalidAnswers {
+                path,
+                line,
+                message,
+            } => write!(f, "{}:{}: {}", path, line, message),
+            Error::MissingAnswers { path, section } => {
+                write!(f, "{} has no [{}] section", path, section)
+            }
+            Error::VerificationFailed => write!(f, "Some answers did not match"),
+        }
+    }
+}
+
+impl Error {
+    // Where in which input a parse failed, naming stdin rather than "-"
+    pub fn invalid_input(path: &str, err: ParseError) -> Self {
+        Error::InvalidInput {
+            path: crate::input_name(path).to_string(),
+            line: err.line,
+            column: Some(err.column),
+            message: err.message,
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use crate::verify::{self, AnswerFile, Outcome};
+use crate::{solve, Solution};
+use std::path::Path;
+
+// Solves every example recorded in the crate's answers.toml through the same
+// parse/solve path as the binary, panicking on any mismatch
+pub fn check_examples<S: Solution>(crate_dir: &str) {
+    let crate_dir = Path::new(crate_dir);
+    let expected = AnswerFile::load(&crate_dir.join("answers.toml")).unwrap();
+    let mut checked = 0;
+    for (section, expected) in &expected.sections {
+        if section == "input" {
+            continue;
+        }
+        let path = crate_dir.join(format!("{}.txt", section));
+        let input = crate::read_full_file(path.to_str().unwrap()).unwrap();
+        let answers = solve::<S>(&input);
+        let parts = ["part one", "part two"];
+        let actual = [&answers.part_one, &answers.part_two];
+        for ((part, outcome), actual) in parts
+            .iter()
+            .zip(verify::check(expected, &answers))
+            .zip(actual)
+        {
+            if let Outcome::Fail { expected } = outcome {
+                panic!(
+                    "{} {}: expected\n{}\ngot\n{}",
+                    section, part, expected, actual
+                );
+            }
+        }
+        checked += 1;
+    }
+    assert!(checked > 0, "answers.toml has no example sections");
+}
+
+// Generates a test running check_examples for the given Solution
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ty) => {
+        #[test]
+        fn examples() {
+            $crate::examples::check_examples::<$solution>(env!("CARGO_MANIFEST_DIR"));
+        }
+    };
+}
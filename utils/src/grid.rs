@@ -0,0 +1,278 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+// (x, y) with the origin in the top left corner
+pub type Point = (usize, usize);
+
+const OFFSETS4: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+const OFFSETS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "cell count must match size");
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn from_fn<F: FnMut(Point) -> T>(width: usize, height: usize, mut f: F) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut f)
+            .collect();
+        Grid::new(width, height, cells)
+    }
+
+    pub fn from_chars<I, S, F>(lines: I, mut f: F) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+        F: FnMut(char) -> T,
+    {
+        match Grid::try_from_chars(lines, |c| Ok::<T, Infallible>(f(c))) {
+            Ok(grid) => grid,
+            Err(never) => match never {},
+        }
+    }
+
+    pub fn try_from_chars<I, S, F, E>(lines: I, mut f: F) -> Result<Self, E>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+        F: FnMut(char) -> Result<T, E>,
+    {
+        let mut width = 0;
+        let mut height = 0;
+        let mut cells = vec![];
+        for line in lines {
+            let line = line.as_ref();
+            let row_start = cells.len();
+            for c in line.chars() {
+                cells.push(f(c)?);
+            }
+            let row_width = cells.len() - row_start;
+            if height == 0 {
+                width = row_width;
+            }
+            assert_eq!(row_width, width, "grid rows must all be the same width");
+            height += 1;
+        }
+        Ok(Grid::new(width, height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): Point) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (x, y): Point) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    // Up, down, left and right, skipping any that fall outside the grid
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> {
+        let (width, height) = (self.width, self.height);
+        OFFSETS4
+            .iter()
+            .filter_map(move |&offset| checked_offset(point, offset, width, height))
+    }
+
+    // As neighbours4, plus the diagonals
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> {
+        let (width, height) = (self.width, self.height);
+        OFFSETS8
+            .iter()
+            .filter_map(move |&offset| checked_offset(point, offset, width, height))
+    }
+
+    // All eight neighbours, wrapping around the edges as if the grid were a torus
+    pub fn wrapping_neighbours(&self, point: Point) -> impl Iterator<Item = Point> {
+        let (width, height) = (self.width, self.height);
+        OFFSETS8
+            .iter()
+            .map(move |&offset| wrapping_offset(point, offset, width, height))
+    }
+
+    pub fn wrapping_offset(&self, point: Point, offset: (isize, isize)) -> Point {
+        wrapping_offset(point, offset, self.width, self.height)
+    }
+}
+
+impl Grid<u32> {
+    pub fn from_digits<I, S>(lines: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Grid::from_chars(lines, |c| c.to_digit(10).expect("Invalid digit"))
+    }
+}
+
+fn checked_offset(
+    (x, y): Point,
+    (dx, dy): (isize, isize),
+    width: usize,
+    height: usize,
+) -> Option<Point> {
+    let x = x.checked_add_signed(dx)?;
+    let y = y.checked_add_signed(dy)?;
+    if x < width && y < height {
+        Some((x, y))
+    } else {
+        None
+    }
+}
+
+fn wrapping_offset((x, y): Point, (dx, dy): (isize, isize), width: usize, height: usize) -> Point {
+    (
+        (x as isize + dx).rem_euclid(width as isize) as usize,
+        (y as isize + dy).rem_euclid(height as isize) as usize,
+    )
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point).expect("Point out of bounds")
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point).expect("Point out of bounds")
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<u32> {
+        Grid::from_digits(["123", "456"])
+    }
+
+    #[test]
+    fn from_digits() {
+        let grid = example();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get((3, 0)), None);
+    }
+
+    #[test]
+    fn neighbours_stay_in_bounds() {
+        let grid = example();
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours8((2, 1)).collect::<Vec<_>>(),
+            [(1, 0), (2, 0), (1, 1)]
+        );
+    }
+
+    #[test]
+    fn wrapping_neighbours() {
+        let grid: Grid<u32> = Grid::from_fn(5, 5, |_| 0);
+        assert_eq!(grid.wrapping_offset((4, 0), (1, 0)), (0, 0));
+        assert_eq!(grid.wrapping_offset((0, 0), (0, -1)), (0, 4));
+        assert_eq!(grid.wrapping_neighbours((0, 0)).count(), 8);
+        assert!(grid.wrapping_neighbours((0, 0)).any(|p| p == (4, 4)));
+    }
+
+    #[test]
+    fn rows_and_display() {
+        let grid = example();
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(grid.to_string(), "123\n456\n");
+    }
+
+    #[test]
+    fn try_from_chars_reports_errors() {
+        let result =
+            Grid::try_from_chars(
+                ["ab", "c!"],
+                |c| {
+                    if c.is_alphabetic() {
+                        Ok(c)
+                    } else {
+                        Err(c)
+                    }
+                },
+            );
+        assert_eq!(result, Err('!'));
+    }
+}
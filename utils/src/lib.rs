@@ -1,24 +1,141 @@
 use std::fs;
-use std::io::{BufRead, BufReader};
+use std::io::{self, BufRead, BufReader, Read};
 
-pub fn read_file(path: &str) -> Vec<String> {
-    let file = fs::File::open(path).expect("Failed to read file");
-    let br = BufReader::new(file);
+pub mod bench;
+pub mod bigint;
+pub mod bits;
+pub mod cli;
+mod error;
+pub mod examples;
+pub mod grid;
+pub mod log;
+pub mod output;
+pub mod parse;
+mod solution;
+pub mod verify;
 
-    br.lines().map(|l| l.expect("Failed to get line")).collect()
+pub use bigint::BigUint;
+pub use bits::BitVec;
+pub use error::Error;
+pub use grid::Grid;
+pub use solution::{
+    lines, main, run, run_with_subcommands, solve, solve_timed, try_solve_timed, Answers, Solution,
+    Subcommand, Timings,
+};
+
+// Passed instead of a path to read the input from stdin
+pub const STDIN: &str = "-";
+
+// How an input path appears in error messages
+pub fn input_name(path: &str) -> &str {
+    if path == STDIN {
+        "stdin"
+    } else {
+        path
+    }
 }
 
-pub fn read_full_file(path: &str) -> String {
-    fs::read_to_string(path).expect("Failed to read file")
+pub fn read_file(path: &str) -> Result<Vec<String>, Error> {
+    Ok(lines(&read_full_file(path)?))
+}
+
+pub fn read_full_file(path: &str) -> Result<String, Error> {
+    let name = input_name(path);
+    let bytes = if path == STDIN {
+        let mut bytes = vec![];
+        io::stdin().read_to_end(&mut bytes).map(|_| bytes)
+    } else {
+        fs::read(path)
+    };
+    let bytes = bytes.map_err(|source| Error::Io {
+        path: name.to_string(),
+        source,
+    })?;
+    let text = String::from_utf8(bytes).map_err(|_| Error::InvalidUtf8 {
+        path: name.to_string(),
+    })?;
+    if text.trim().is_empty() {
+        return Err(Error::EmptyInput {
+            path: name.to_string(),
+        });
+    }
+    Ok(text)
 }
 
-pub fn binary_to_decimal(bools: Vec<bool>) -> u32 {
-    let mut acc: u32 = 0;
-    for i in bools {
-        match i {
-            true => acc = (acc * 2) + 1,
-            false => acc *= 2,
-        }
+pub fn write_file(path: &str, contents: impl AsRef<[u8]>) -> Result<(), Error> {
+    fs::write(path, contents).map_err(|source| Error::Io {
+        path: path.to_string(),
+        source,
+    })
+}
+
+// Reads lines lazily so inputs too large for memory can still be processed
+pub fn stream_lines(path: &str) -> Result<impl Iterator<Item = Result<String, Error>>, Error> {
+    let name = input_name(path).to_string();
+    let reader: Box<dyn BufRead> = if path == STDIN {
+        Box::new(BufReader::new(io::stdin()))
+    } else {
+        let file = fs::File::open(path).map_err(|source| Error::Io {
+            path: name.clone(),
+            source,
+        })?;
+        Box::new(BufReader::new(file))
+    };
+    Ok(reader.lines().map(move |line| {
+        line.map_err(|source| Error::Io {
+            path: name.clone(),
+            source,
+        })
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn temp_file(name: &str, contents: &[u8]) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("utils-{}-{}", std::process::id(), name));
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn read_file_lines() {
+        let path = temp_file("lines.txt", b"199\n200\n");
+        let lines = read_file(path.to_str().unwrap()).unwrap();
+        assert_eq!(lines, vec!["199", "200"]);
+    }
+
+    #[test]
+    fn stream_file_lines() {
+        let path = temp_file("stream.txt", b"199\n200\n");
+        let lines: Result<Vec<String>, Error> =
+            stream_lines(path.to_str().unwrap()).unwrap().collect();
+        assert_eq!(lines.unwrap(), vec!["199", "200"]);
+        assert!(matches!(
+            stream_lines("does/not/exist.txt").map(|_| ()),
+            Err(Error::Io { .. })
+        ));
+    }
+
+    #[test]
+    fn missing_file_is_io_error() {
+        let result = read_full_file("does/not/exist.txt");
+        assert!(matches!(result, Err(Error::Io { .. })));
+    }
+
+    #[test]
+    fn empty_file_is_empty_input() {
+        let path = temp_file("empty.txt", b"\n\n");
+        let result = read_full_file(path.to_str().unwrap());
+        assert!(matches!(result, Err(Error::EmptyInput { .. })));
+    }
+
+    #[test]
+    fn invalid_utf8_is_reported() {
+        let path = temp_file("binary.txt", &[0x66, 0x6f, 0xff, 0xfe]);
+        let result = read_full_file(path.to_str().unwrap());
+        assert!(matches!(result, Err(Error::InvalidUtf8 { .. })));
     }
-    acc
 }
@@ -0,0 +1,124 @@
+use std::fmt;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error = 1,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    fn name(self) -> &'static str {
+        match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        }
+    }
+}
+
+// The most verbose level that gets written, 0 meaning logging is off
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(Level::Warn as u8);
+
+const LEVELS: [(&str, u8); 6] = [
+    ("off", 0),
+    ("error", Level::Error as u8),
+    ("warn", Level::Warn as u8),
+    ("info", Level::Info as u8),
+    ("debug", Level::Debug as u8),
+    ("trace", Level::Trace as u8),
+];
+
+struct Filter(u8);
+
+impl FromStr for Filter {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        LEVELS
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(s.trim()))
+            .map(|&(_, level)| Filter(level))
+            .ok_or(())
+    }
+}
+
+// Each -v raises the level one step from warn, overriding RUST_LOG
+fn max_level(verbose: u8, rust_log: Option<&str>) -> u8 {
+    if verbose > 0 {
+        return (Level::Warn as u8 + verbose).min(Level::Trace as u8);
+    }
+    rust_log
+        .and_then(|value| value.parse().ok())
+        .map_or(Level::Warn as u8, |Filter(level)| level)
+}
+
+pub fn init(verbose: u8) {
+    let rust_log = std::env::var("RUST_LOG").ok();
+    MAX_LEVEL.store(max_level(verbose, rust_log.as_deref()), Ordering::Relaxed);
+}
+
+pub fn enabled(level: Level) -> bool {
+    level as u8 <= MAX_LEVEL.load(Ordering::Relaxed)
+}
+
+// Logs go to stderr so that stdout only ever holds the answers
+pub fn write(level: Level, target: &str, args: fmt::Arguments) {
+    eprintln!("[{} {}] {}", level.name(), target, args);
+}
+
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log::enabled($level) {
+            $crate::log::write($level, module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verbosity_overrides_rust_log() {
+        assert_eq!(max_level(0, None), Level::Warn as u8);
+        assert_eq!(max_level(1, Some("error")), Level::Info as u8);
+        assert_eq!(max_level(2, None), Level::Debug as u8);
+        assert_eq!(max_level(5, None), Level::Trace as u8);
+    }
+
+    #[test]
+    fn rust_log_levels() {
+        assert_eq!(max_level(0, Some("debug")), Level::Debug as u8);
+        assert_eq!(max_level(0, Some("TRACE")), Level::Trace as u8);
+        assert_eq!(max_level(0, Some("off")), 0);
+        assert_eq!(max_level(0, Some("loud")), Level::Warn as u8);
+    }
+}
@@ -0,0 +1,94 @@
+use crate::solution::{Answers, Timings};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("unknown format {}", s)),
+        }
+    }
+}
+
+// One JSON object per day and part, with elapsed in nanoseconds
+pub fn to_json(results: &[(u8, Answers, Timings)]) -> String {
+    let records: Vec<String> = results
+        .iter()
+        .flat_map(|(day, answers, timings)| {
+            [
+                (1, &answers.part_one, timings.part_one),
+                (2, &answers.part_two, timings.part_two),
+            ]
+            .map(|(part, answer, elapsed)| {
+                format!(
+                    "  {{\"day\": {}, \"part\": {}, \"answer\": {}, \"elapsed\": {}}}",
+                    day,
+                    part,
+                    json_string(answer),
+                    elapsed.as_nanos()
+                )
+            })
+        })
+        .collect();
+    if records.is_empty() {
+        return String::from("[]\n");
+    }
+    format!("[\n{}\n]\n", records.join(",\n"))
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn json_records() {
+        let answers = Answers {
+            part_one: String::from("17"),
+            part_two: String::from("# \"#\"\n\\#"),
+        };
+        let timings = Timings {
+            parse: Duration::from_nanos(5),
+            part_one: Duration::from_nanos(1200),
+            part_two: Duration::from_nanos(30),
+        };
+        assert_eq!(
+            to_json(&[(13, answers, timings)]),
+            "[\n  {\"day\": 13, \"part\": 1, \"answer\": \"17\", \"elapsed\": 1200},\n  \
+             {\"day\": 13, \"part\": 2, \"answer\": \"# \\\"#\\\"\\n\\\\#\", \"elapsed\": 30}\n]\n"
+        );
+        assert_eq!(to_json(&[]), "[]\n");
+    }
+
+    #[test]
+    fn escape_control_characters() {
+        assert_eq!(json_string("a\tb\u{1}"), "\"a\\tb\\u0001\"");
+    }
+}
@@ -0,0 +1,290 @@
+use std::fmt;
+use std::iter;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub type Result<T> = std::result::Result<T, ParseError>;
+
+// A cursor over part of the puzzle input. Parsers for single lines or sections
+// still point into the whole input, so errors report absolute positions.
+#[derive(Debug, Clone, Copy)]
+pub struct Parser<'a> {
+    source: &'a str,
+    pos: usize,
+    end: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Parser {
+            source,
+            pos: 0,
+            end: source.len(),
+        }
+    }
+
+    pub fn rest(&self) -> &'a str {
+        &self.source[self.pos..self.end]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pos == self.end
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    pub fn error<T>(&self, message: impl Into<String>) -> Result<T> {
+        let before = &self.source[..self.pos];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        Err(ParseError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            message: message.into(),
+        })
+    }
+
+    // Consumes the literal if the input starts with it
+    pub fn eat(&mut self, literal: &str) -> bool {
+        if self.rest().starts_with(literal) {
+            self.pos += literal.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn expect(&mut self, literal: &str) -> Result<()> {
+        if self.eat(literal) {
+            Ok(())
+        } else {
+            self.error(format!("expected `{}`", literal))
+        }
+    }
+
+    pub fn take_while<F: Fn(char) -> bool>(&mut self, f: F) -> &'a str {
+        let rest = self.rest();
+        let len = rest.find(|c| !f(c)).unwrap_or(rest.len());
+        self.pos += len;
+        &rest[..len]
+    }
+
+    pub fn word(&mut self) -> Result<&'a str> {
+        match self.take_while(|c| c.is_ascii_alphanumeric()) {
+            "" => self.error("expected a word"),
+            word => Ok(word),
+        }
+    }
+
+    // Matches the first of the given keywords, returning its value
+    pub fn one_of<T: Copy>(&mut self, options: &[(&str, T)]) -> Result<T> {
+        for &(keyword, value) in options {
+            if self.eat(keyword) {
+                return Ok(value);
+            }
+        }
+        let keywords: Vec<String> = options.iter().map(|(k, _)| format!("`{}`", k)).collect();
+        self.error(format!("expected one of {}", keywords.join(", ")))
+    }
+
+    pub fn unsigned<T: FromStr>(&mut self) -> Result<T> {
+        let start = *self;
+        match self.take_while(|c| c.is_ascii_digit()) {
+            "" => start.error("expected a number"),
+            digits => digits
+                .parse()
+                .or_else(|_| start.error(format!("number {} is out of range", digits))),
+        }
+    }
+
+    pub fn signed<T: FromStr>(&mut self) -> Result<T> {
+        let start = *self;
+        let negative = self.eat("-");
+        if !negative {
+            self.eat("+");
+        }
+        let digits = self.take_while(|c| c.is_ascii_digit());
+        if digits.is_empty() {
+            return self.error("expected a number");
+        }
+        let number = &start.rest()[..self.pos - start.pos];
+        number
+            .parse()
+            .or_else(|_| start.error(format!("number {} is out of range", number)))
+    }
+
+    // One or more items with the separator between each
+    pub fn separated<T, F>(&mut self, separator: &str, mut f: F) -> Result<Vec<T>>
+    where
+        F: FnMut(&mut Parser<'a>) -> Result<T>,
+    {
+        let mut items = vec![f(self)?];
+        while self.eat(separator) {
+            items.push(f(self)?);
+        }
+        Ok(items)
+    }
+
+    // `a..b`, returning the bounds as written
+    pub fn range<T, F>(&mut self, mut f: F) -> Result<(T, T)>
+    where
+        F: FnMut(&mut Parser<'a>) -> Result<T>,
+    {
+        let from = f(self)?;
+        self.expect("..")?;
+        Ok((from, f(self)?))
+    }
+
+    // `key=value`, parsing the value with f
+    pub fn key_value<T, F>(&mut self, key: &str, f: F) -> Result<T>
+    where
+        F: FnOnce(&mut Parser<'a>) -> Result<T>,
+    {
+        self.expect(key)?;
+        self.expect("=")?;
+        f(self)
+    }
+
+    pub fn finish(&self) -> Result<()> {
+        match self.peek() {
+            None => Ok(()),
+            Some(_) => self.error(format!("unexpected `{}`", self.rest())),
+        }
+    }
+
+    // Runs f, requiring it to consume everything
+    pub fn complete<T, F>(mut self, f: F) -> Result<T>
+    where
+        F: FnOnce(&mut Parser<'a>) -> Result<T>,
+    {
+        let value = f(&mut self)?;
+        self.finish()?;
+        Ok(value)
+    }
+
+    // The remaining input split like str::lines
+    pub fn lines(&self) -> impl Iterator<Item = Parser<'a>> {
+        let (source, end) = (self.source, self.end);
+        let mut start = self.pos;
+        iter::from_fn(move || {
+            if start >= end {
+                return None;
+            }
+            let line_end = source[start..end].find('\n').map_or(end, |i| start + i);
+            let line = Parser {
+                source,
+                pos: start,
+                end: line_end - source[start..line_end].ends_with('\r') as usize,
+            };
+            start = line_end + 1;
+            Some(line)
+        })
+    }
+
+    // Completely parses every line with f
+    pub fn each_line<T, F>(&self, mut f: F) -> Result<Vec<T>>
+    where
+        F: FnMut(&mut Parser<'a>) -> Result<T>,
+    {
+        self.lines().map(|line| line.complete(&mut f)).collect()
+    }
+
+    // Runs of non-blank lines separated by blank ones
+    pub fn sections(&self) -> impl Iterator<Item = Parser<'a>> {
+        let mut lines = self.lines().peekable();
+        iter::from_fn(move || {
+            let first = lines.find(|line| !line.rest().trim().is_empty())?;
+            let mut last = first;
+            while let Some(line) = lines.next_if(|line| !line.rest().trim().is_empty()) {
+                last = line;
+            }
+            Some(Parser {
+                end: last.end,
+                ..first
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers() {
+        let mut p = Parser::new("42,-7,+3");
+        assert_eq!(p.unsigned::<u32>(), Ok(42));
+        p.expect(",").unwrap();
+        assert_eq!(p.signed::<i32>(), Ok(-7));
+        p.expect(",").unwrap();
+        assert_eq!(p.signed::<i32>(), Ok(3));
+        assert!(p.is_empty());
+    }
+
+    #[test]
+    fn ranges_and_key_values() {
+        let target = Parser::new("x=20..30, y=-10..-5").complete(|p| {
+            let x = p.key_value("x", |p| p.range(Parser::signed::<i32>))?;
+            p.expect(", ")?;
+            let y = p.key_value("y", |p| p.range(Parser::signed::<i32>))?;
+            Ok((x, y))
+        });
+        assert_eq!(target, Ok(((20, 30), (-10, -5))));
+    }
+
+    #[test]
+    fn separated_lists() {
+        let list = Parser::new("3,4,3,1,2").complete(|p| p.separated(",", Parser::unsigned));
+        assert_eq!(list, Ok(vec![3u8, 4, 3, 1, 2]));
+    }
+
+    #[test]
+    fn sections_and_lines() {
+        let input = "1,2\n3,4\n\n\nfold x\n";
+        let sections: Vec<Parser> = Parser::new(input).sections().collect();
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[0].rest(), "1,2\n3,4");
+        let points = sections[0].each_line(|p| p.separated(",", Parser::unsigned::<u8>));
+        assert_eq!(points, Ok(vec![vec![1, 2], vec![3, 4]]));
+        assert_eq!(sections[1].rest(), "fold x");
+    }
+
+    #[test]
+    fn errors_carry_positions() {
+        let input = "on x=1..2\noff x=1...2\n";
+        let result = Parser::new(input).each_line(|p| {
+            p.one_of(&[("on", true), ("off", false)])?;
+            p.expect(" ")?;
+            p.key_value("x", |p| p.range(Parser::signed::<i32>))
+        });
+        let err = result.unwrap_err();
+        assert_eq!((err.line, err.column), (2, 10));
+        assert_eq!(err.to_string(), "line 2, column 10: expected a number");
+
+        let err = Parser::new("300").unsigned::<u8>().unwrap_err();
+        assert_eq!(err.message, "number 300 is out of range");
+        let err = Parser::new("up 3")
+            .one_of(&[("on", 1), ("off", 0)])
+            .unwrap_err();
+        assert_eq!(err.message, "expected one of `on`, `off`");
+    }
+}
@@ -0,0 +1,152 @@
+use crate::bench;
+use crate::cli::Options;
+use crate::output::{self, Format};
+use crate::parse;
+use crate::verify::{self, AnswerFile};
+use crate::Error;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+
+    fn parse(input: &str) -> Self::Input;
+    fn part_one(input: &Self::Input) -> String;
+    fn part_two(input: &Self::Input) -> String;
+
+    // Days whose input can be rejected with a position override this, so
+    // that run reports the mistake instead of panicking
+    fn try_parse(input: &str) -> parse::Result<Self::Input> {
+        Ok(Self::parse(input))
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub part_one: String,
+    pub part_two: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part_one: Duration,
+    pub part_two: Duration,
+}
+
+pub fn solve<S: Solution>(input: &str) -> Answers {
+    solve_timed::<S>(input).0
+}
+
+pub fn solve_timed<S: Solution>(input: &str) -> (Answers, Timings) {
+    try_solve_timed::<S>(input).unwrap_or_else(|err| panic!("Invalid input: {}", err))
+}
+
+pub fn try_solve_timed<S: Solution>(input: &str) -> parse::Result<(Answers, Timings)> {
+    let start = Instant::now();
+    let parsed = S::try_parse(input)?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let part_one = S::part_one(&parsed);
+    let part_one_elapsed = start.elapsed();
+
+    let start = Instant::now();
+    let part_two = S::part_two(&parsed);
+    let part_two_elapsed = start.elapsed();
+
+    Ok((
+        Answers { part_one, part_two },
+        Timings {
+            parse,
+            part_one: part_one_elapsed,
+            part_two: part_two_elapsed,
+        },
+    ))
+}
+
+pub fn run<S: Solution>() -> Result<(), Error> {
+    let options = Options::from_env()?;
+    crate::log::init(options.verbose);
+    let input = crate::read_full_file(options.input_path())?;
+    let invalid = |err| Error::invalid_input(options.input_path(), err);
+
+    if let Some(runs) = options.bench {
+        S::try_parse(&input).map_err(invalid)?;
+        let benchmarks = [bench::bench(S::DAY, solve_timed::<S>, &input, runs)];
+        print!("{}", bench::render(&benchmarks));
+        bench::write_report(
+            options.report.as_deref().unwrap_or("bench.json"),
+            &benchmarks,
+        )?;
+        return Ok(());
+    }
+
+    let (answers, timings) = try_solve_timed::<S>(&input).map_err(invalid)?;
+    if options.verify {
+        return verify_answers(options.input_path(), &answers);
+    }
+    match options.format {
+        Format::Text => {
+            println!("Part one: {}", answers.part_one);
+            println!("Part two: {}", answers.part_two);
+        }
+        Format::Json => print!("{}", output::to_json(&[(S::DAY, answers, timings)])),
+    }
+    Ok(())
+}
+
+pub type Subcommand = fn(&[String]) -> Result<(), Error>;
+
+// For days with extra tools: runs the subcommand named by the first argument,
+// or the puzzle as usual, exiting with the error if either fails
+pub fn run_with_subcommands<S: Solution>(subcommands: &[(&str, Subcommand)]) {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let subcommand = subcommands
+        .iter()
+        .find(|(name, _)| args.first().map(String::as_str) == Some(*name));
+    let result = match subcommand {
+        Some((_, subcommand)) => subcommand(&args[1..]),
+        None => run::<S>(),
+    };
+    if let Err(err) = result {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    }
+}
+
+// A day's main when it has no extra tools
+pub fn main<S: Solution>() {
+    run_with_subcommands::<S>(&[]);
+}
+
+// Expected answers live next to the input, in a section named after its file stem
+fn verify_answers(input_path: &str, answers: &Answers) -> Result<(), Error> {
+    if input_path == crate::STDIN {
+        return Err(Error::InvalidArgument(String::from(
+            "--verify needs an input path, not stdin",
+        )));
+    }
+    let input_path = Path::new(input_path);
+    let answers_path = input_path.with_file_name("answers.toml");
+    let section = input_path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or_default();
+    let expected = AnswerFile::load(&answers_path)?;
+    let expected = expected.get(section).ok_or_else(|| Error::MissingAnswers {
+        path: answers_path.display().to_string(),
+        section: section.to_string(),
+    })?;
+    let outcomes = verify::check(expected, answers);
+    if verify::report(section, answers, &outcomes) {
+        Ok(())
+    } else {
+        Err(Error::VerificationFailed)
+    }
+}
+
+pub fn lines(input: &str) -> Vec<String> {
+    input.lines().map(String::from).collect()
+}
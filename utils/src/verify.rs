@@ -0,0 +1,239 @@
+use crate::{Answers, Error};
+use std::fs;
+use std::path::Path;
+
+// Expected answers for one input file; either part may be left unrecorded
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+// The contents of an answers.toml, one section per input file stem:
+//
+//   [input]
+//   part_one = 1696
+//   part_two = """
+//   # #
+//   ###
+//   """
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct AnswerFile {
+    pub sections: Vec<(String, Expected)>,
+}
+
+impl AnswerFile {
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let display = path.display().to_string();
+        let text = fs::read_to_string(path).map_err(|source| Error::Io {
+            path: display.clone(),
+            source,
+        })?;
+        AnswerFile::parse(&text).map_err(|(line, message)| Error::InvalidAnswers {
+            path: display,
+            line,
+            message,
+        })
+    }
+
+    pub fn get(&self, section: &str) -> Option<&Expected> {
+        self.sections
+            .iter()
+            .find(|(name, _)| name == section)
+            .map(|(_, expected)| expected)
+    }
+
+    fn parse(text: &str) -> Result<Self, (usize, String)> {
+        let mut file = AnswerFile::default();
+        let mut lines = text.lines().enumerate().map(|(i, l)| (i + 1, l));
+        while let Some((number, line)) = lines.next() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                file.sections
+                    .push((name.trim().to_string(), Expected::default()));
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or((number, String::from("expected `key = value`")))?;
+            let value = value.trim();
+            let value = if let Some(rest) = value.strip_prefix("\"\"\"") {
+                parse_multiline(rest, &mut lines)
+                    .ok_or((number, String::from("unclosed \"\"\"")))?
+            } else if let Some(quoted) = value.strip_prefix('"') {
+                parse_basic(quoted).ok_or((number, String::from("bad string")))?
+            } else if !value.is_empty() && value.chars().all(|c| c.is_ascii_digit() || c == '-') {
+                value.to_string()
+            } else {
+                return Err((number, format!("unsupported value `{}`", value)));
+            };
+            let (_, expected) = file
+                .sections
+                .last_mut()
+                .ok_or((number, String::from("answer outside of a [section]")))?;
+            match key.trim() {
+                "part_one" => expected.part_one = Some(value),
+                "part_two" => expected.part_two = Some(value),
+                other => return Err((number, format!("unknown key `{}`", other))),
+            }
+        }
+        Ok(file)
+    }
+}
+
+fn parse_basic(quoted: &str) -> Option<String> {
+    let mut value = String::new();
+    let mut chars = quoted.chars();
+    loop {
+        match chars.next()? {
+            '"' => return chars.as_str().trim().is_empty().then_some(value),
+            '\\' => value.push(match chars.next()? {
+                'n' => '\n',
+                't' => '\t',
+                c @ ('"' | '\\') => c,
+                _ => return None,
+            }),
+            c => value.push(c),
+        }
+    }
+}
+
+// A newline straight after the opening quotes is not part of the value
+fn parse_multiline<'a, I>(rest: &str, lines: &mut I) -> Option<String>
+where
+    I: Iterator<Item = (usize, &'a str)>,
+{
+    if let Some(end) = rest.find("\"\"\"") {
+        return Some(rest[..end].to_string());
+    }
+    let mut value = rest.to_string();
+    let mut first = true;
+    for (_, line) in lines {
+        if !(first && value.is_empty()) {
+            value.push('\n');
+        }
+        first = false;
+        if let Some(end) = line.find("\"\"\"") {
+            value.push_str(&line[..end]);
+            return Some(value);
+        }
+        value.push_str(line);
+    }
+    None
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Fail { expected: String },
+    Unrecorded,
+}
+
+pub fn check(expected: &Expected, answers: &Answers) -> [Outcome; 2] {
+    [
+        outcome(expected.part_one.as_deref(), &answers.part_one),
+        outcome(expected.part_two.as_deref(), &answers.part_two),
+    ]
+}
+
+fn outcome(expected: Option<&str>, actual: &str) -> Outcome {
+    match expected {
+        None => Outcome::Unrecorded,
+        Some(expected) if normalise(expected) == normalise(actual) => Outcome::Pass,
+        Some(expected) => Outcome::Fail {
+            expected: expected.to_string(),
+        },
+    }
+}
+
+// Rendered answers (day 13) carry trailing spaces that editors like to strip
+fn normalise(answer: &str) -> String {
+    let lines: Vec<&str> = answer.lines().map(str::trim_end).collect();
+    lines.join("\n").trim().to_string()
+}
+
+// Prints one line per part and returns whether everything recorded passed
+pub fn report(label: &str, answers: &Answers, outcomes: &[Outcome; 2]) -> bool {
+    let actual = [&answers.part_one, &answers.part_two];
+    let mut passed = true;
+    for ((part, outcome), actual) in ["part one", "part two"].iter().zip(outcomes).zip(actual) {
+        match outcome {
+            Outcome::Pass => println!("{} {}: pass", label, part),
+            Outcome::Unrecorded => println!("{} {}: no answer recorded", label, part),
+            Outcome::Fail { expected } => {
+                passed = false;
+                println!(
+                    "{} {}: FAIL (expected {}, got {})",
+                    label,
+                    part,
+                    normalise(expected),
+                    normalise(actual)
+                );
+            }
+        }
+    }
+    passed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_sections() {
+        let file = AnswerFile::parse(
+            "# day 13\n[input]\npart_one = 759\npart_two = \"\"\"\n# #\n###\n\"\"\"\n\n[example]\npart_one = \"17\"\n",
+        )
+        .unwrap();
+        assert_eq!(
+            file.get("input"),
+            Some(&Expected {
+                part_one: Some(String::from("759")),
+                part_two: Some(String::from("# #\n###\n")),
+            })
+        );
+        assert_eq!(file.get("example").unwrap().part_two, None);
+        assert_eq!(file.get("example2"), None);
+    }
+
+    #[test]
+    fn parse_errors_carry_line_numbers() {
+        assert_eq!(
+            AnswerFile::parse("[input]\npart_three = 1\n"),
+            Err((2, String::from("unknown key `part_three`")))
+        );
+        assert_eq!(
+            AnswerFile::parse("part_one = 1\n"),
+            Err((1, String::from("answer outside of a [section]")))
+        );
+    }
+
+    #[test]
+    fn check_answers() {
+        let expected = Expected {
+            part_one: Some(String::from("7")),
+            part_two: None,
+        };
+        let answers = Answers {
+            part_one: String::from("8"),
+            part_two: String::from("5"),
+        };
+        assert_eq!(
+            check(&expected, &answers),
+            [
+                Outcome::Fail {
+                    expected: String::from("7")
+                },
+                Outcome::Unrecorded
+            ]
+        );
+    }
+
+    #[test]
+    fn trailing_whitespace_is_ignored() {
+        assert_eq!(outcome(Some("# #\n###"), "# # \n###\n"), Outcome::Pass);
+    }
+}